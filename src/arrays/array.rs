@@ -1,11 +1,19 @@
 use crate::arrays::generic_array::GenericArray;
-use crate::arrays::{DecimalElt, IntegerElt};
+use crate::arrays::noun::Noun;
+use crate::arrays::{ComplexElt, DecimalElt, ExtendedElt, IntegerElt, RationalElt};
+use std::cmp::Ordering;
+use std::fmt::Debug;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Array {
     Boolean(GenericArray<bool>),
     Integer(GenericArray<IntegerElt>),
+    Extended(GenericArray<ExtendedElt>),
+    Rational(GenericArray<RationalElt>),
     Decimal(GenericArray<DecimalElt>),
+    Complex(GenericArray<ComplexElt>),
+    Character(GenericArray<char>),
+    Boxed(GenericArray<Box<Noun>>),
 }
 
 impl From<GenericArray<bool>> for Array {
@@ -20,19 +28,54 @@ impl From<GenericArray<IntegerElt>> for Array {
     }
 }
 
+impl From<GenericArray<ExtendedElt>> for Array {
+    fn from(w: GenericArray<ExtendedElt>) -> Self {
+        Self::Extended(w)
+    }
+}
+
+impl From<GenericArray<RationalElt>> for Array {
+    fn from(w: GenericArray<RationalElt>) -> Self {
+        Self::Rational(w)
+    }
+}
+
 impl From<GenericArray<DecimalElt>> for Array {
     fn from(w: GenericArray<DecimalElt>) -> Self {
         Self::Decimal(w)
     }
 }
 
+impl From<GenericArray<ComplexElt>> for Array {
+    fn from(w: GenericArray<ComplexElt>) -> Self {
+        Self::Complex(w)
+    }
+}
+
+impl From<GenericArray<char>> for Array {
+    fn from(w: GenericArray<char>) -> Self {
+        Self::Character(w)
+    }
+}
+
+impl From<GenericArray<Box<Noun>>> for Array {
+    fn from(w: GenericArray<Box<Noun>>) -> Self {
+        Self::Boxed(w)
+    }
+}
+
 impl Array {
     pub fn shape(&self) -> &[usize] {
         use Array::*;
         match self {
             Boolean(b) => b.shape(),
             Integer(i) => i.shape(),
+            Extended(x) => x.shape(),
+            Rational(r) => r.shape(),
             Decimal(d) => d.shape(),
+            Complex(j) => j.shape(),
+            Character(c) => c.shape(),
+            Boxed(b) => b.shape(),
         }
     }
 
@@ -41,7 +84,65 @@ impl Array {
         match self {
             Boolean(b) => b.rank(),
             Integer(i) => i.rank(),
+            Extended(x) => x.rank(),
+            Rational(r) => r.rank(),
             Decimal(d) => d.rank(),
+            Complex(j) => j.rank(),
+            Character(c) => c.rank(),
+            Boxed(b) => b.rank(),
         }
     }
+
+    /// The total array ordering: numbers come before characters, which come before boxes.
+    /// Otherwise arrays are ordered by their elements in turn, then by shape.
+    pub fn total_cmp(&self, other: &Array) -> Ordering {
+        use Array::*;
+        let elements = match (self, other) {
+            (Boolean(a), Boolean(w)) => lexical(a, w, Ord::cmp),
+            (Integer(a), Integer(w)) => lexical(a, w, Ord::cmp),
+            (Extended(a), Extended(w)) => lexical(a, w, Ord::cmp),
+            (Rational(a), Rational(w)) => lexical(a, w, Ord::cmp),
+            (Decimal(a), Decimal(w)) => lexical(a, w, DecimalElt::total_cmp),
+            (Complex(a), Complex(w)) => lexical(a, w, |a, w| {
+                a.re.total_cmp(&w.re).then(a.im.total_cmp(&w.im))
+            }),
+            (Character(a), Character(w)) => lexical(a, w, Ord::cmp),
+            (Boxed(a), Boxed(w)) => lexical(a, w, |a, w| a.total_cmp(w)),
+            (a, w) => {
+                let (a, w) = (Noun::from(a.clone()), Noun::from(w.clone()));
+                match (a.numeric(), w.numeric()) {
+                    // Numbers of different types are compared in their common type
+                    (Some(ta), Some(tw)) => {
+                        let to = ta.max(tw);
+                        let a = a.promote_to(to).into_array();
+                        return a.total_cmp(&w.promote_to(to).into_array());
+                    }
+                    _ => class(self).cmp(&class(other)),
+                }
+            }
+        };
+        elements.then_with(|| self.shape().cmp(other.shape()))
+    }
+}
+
+fn class(w: &Array) -> u8 {
+    match w {
+        Array::Character(_) => 1,
+        Array::Boxed(_) => 2,
+        _ => 0,
+    }
+}
+
+/// Compare elements in turn, with a prefix ordered first.
+fn lexical<T, F>(a: &GenericArray<T>, w: &GenericArray<T>, f: F) -> Ordering
+where
+    T: Clone + Debug,
+    F: Fn(&T, &T) -> Ordering,
+{
+    let (a, w) = (a.raw_data(), w.raw_data());
+    a.iter()
+        .zip(w)
+        .map(|(a, w)| f(a, w))
+        .find(|o| o.is_ne())
+        .unwrap_or(a.len().cmp(&w.len()))
 }
@@ -1,10 +1,11 @@
 use crate::arrays::generic_array::GenericArray;
 use std::fmt::Debug;
+use std::slice;
 
 #[derive(Debug, Clone)]
 pub enum ArrayOrAtom<T>
 where
-    T: Copy + Debug,
+    T: Clone + Debug,
 {
     Array(GenericArray<T>),
     Atom(T),
@@ -12,7 +13,7 @@ where
 
 impl<T> From<T> for ArrayOrAtom<T>
 where
-    T: Copy + Debug,
+    T: Clone + Debug,
 {
     fn from(w: T) -> Self {
         ArrayOrAtom::Atom(w)
@@ -21,9 +22,22 @@ where
 
 impl<T> From<GenericArray<T>> for ArrayOrAtom<T>
 where
-    T: Copy + Debug,
+    T: Clone + Debug,
 {
     fn from(w: GenericArray<T>) -> Self {
         ArrayOrAtom::Array(w)
     }
 }
+
+impl<T> ArrayOrAtom<T>
+where
+    T: Clone + Debug,
+{
+    /// The elements of the array, or the atom alone.
+    pub fn elements(&self) -> &[T] {
+        match self {
+            ArrayOrAtom::Array(w) => w.raw_data(),
+            ArrayOrAtom::Atom(w) => slice::from_ref(w),
+        }
+    }
+}
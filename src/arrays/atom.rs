@@ -1,10 +1,16 @@
-use crate::arrays::{DecimalElt, IntegerElt};
+use crate::arrays::noun::Noun;
+use crate::arrays::{ComplexElt, DecimalElt, ExtendedElt, IntegerElt, RationalElt};
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Atom {
     Boolean(bool),
     Integer(IntegerElt),
+    Extended(ExtendedElt),
+    Rational(RationalElt),
     Decimal(DecimalElt),
+    Complex(ComplexElt),
+    Character(char),
+    Boxed(Box<Noun>),
 }
 
 impl From<bool> for Atom {
@@ -19,8 +25,38 @@ impl From<IntegerElt> for Atom {
     }
 }
 
+impl From<ExtendedElt> for Atom {
+    fn from(w: ExtendedElt) -> Self {
+        Self::Extended(w)
+    }
+}
+
+impl From<RationalElt> for Atom {
+    fn from(w: RationalElt) -> Self {
+        Self::Rational(w)
+    }
+}
+
 impl From<DecimalElt> for Atom {
     fn from(w: DecimalElt) -> Self {
         Self::Decimal(w)
     }
 }
+
+impl From<ComplexElt> for Atom {
+    fn from(w: ComplexElt) -> Self {
+        Self::Complex(w)
+    }
+}
+
+impl From<char> for Atom {
+    fn from(w: char) -> Self {
+        Self::Character(w)
+    }
+}
+
+impl From<Box<Noun>> for Atom {
+    fn from(w: Box<Noun>) -> Self {
+        Self::Boxed(w)
+    }
+}
@@ -0,0 +1,66 @@
+use crate::arrays::generic_array::GenericArray;
+use crate::arrays::noun::Noun;
+use crate::arrays::{ComplexElt, DecimalElt, ExtendedElt, IntegerElt, RationalElt};
+use num::Zero;
+
+/// The element used to pad arrays out to a larger shape.
+pub trait Fill {
+    fn fill() -> Self;
+}
+
+impl Fill for bool {
+    #[inline]
+    fn fill() -> Self {
+        false
+    }
+}
+
+impl Fill for IntegerElt {
+    #[inline]
+    fn fill() -> Self {
+        0
+    }
+}
+
+impl Fill for ExtendedElt {
+    #[inline]
+    fn fill() -> Self {
+        ExtendedElt::zero()
+    }
+}
+
+impl Fill for RationalElt {
+    #[inline]
+    fn fill() -> Self {
+        RationalElt::zero()
+    }
+}
+
+impl Fill for DecimalElt {
+    #[inline]
+    fn fill() -> Self {
+        0.0
+    }
+}
+
+impl Fill for ComplexElt {
+    #[inline]
+    fn fill() -> Self {
+        ComplexElt::zero()
+    }
+}
+
+impl Fill for char {
+    #[inline]
+    fn fill() -> Self {
+        ' '
+    }
+}
+
+impl Fill for Box<Noun> {
+    /// The empty box, holding an empty list.
+    #[inline]
+    fn fill() -> Self {
+        Box::new(Noun::from(GenericArray::<bool>::new(vec![])))
+    }
+}
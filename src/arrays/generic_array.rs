@@ -1,15 +1,16 @@
+use crate::arrays::array_or_atom::ArrayOrAtom;
+use crate::arrays::fill::Fill;
 use crate::arrays::IntegerElt;
-use anyhow::{anyhow, Context};
+use anyhow::anyhow;
 use itertools::Itertools;
 use std::cmp::Ordering;
-use std::fmt::{Debug, Display};
+use std::fmt::Debug;
 use std::iter::zip;
-use std::str::FromStr;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct GenericArray<T>
 where
-    T: Copy + Debug,
+    T: Clone + Debug,
 {
     shape: Vec<usize>,
     data: Vec<T>,
@@ -17,7 +18,7 @@ where
 
 impl<T> GenericArray<T>
 where
-    T: Copy + Debug,
+    T: Clone + Debug,
 {
     pub fn new<C: Into<Vec<T>>>(c: C) -> Self {
         let data = c.into();
@@ -25,8 +26,33 @@ where
         Self { shape, data }
     }
 
+    /// An array of the given shape, which must hold exactly as many elements as `data`.
+    pub fn from_shape_and_data(shape: Vec<usize>, data: Vec<T>) -> anyhow::Result<Self> {
+        if shape.iter().product::<usize>() != data.len() {
+            return Err(anyhow!(
+                "Length error: {} elements do not fill shape {}",
+                data.len(),
+                shape.iter().join(" ")
+            ));
+        }
+        Ok(Self { shape, data })
+    }
+
+    /// The same elements arranged in a new shape, which must hold exactly as many.
+    pub fn with_shape(self, shape: Vec<usize>) -> anyhow::Result<Self> {
+        Self::from_shape_and_data(shape, self.data)
+    }
+
+    /// An array of rank 0 holding a single atom.
+    pub fn scalar(w: T) -> Self {
+        Self {
+            shape: vec![],
+            data: vec![w],
+        }
+    }
+
     fn get_index(&self, index: &[usize]) -> Option<usize> {
-        if index.len() != self.rank() {
+        if index.len() != self.rank() || zip(index, &self.shape).any(|(i, n)| i >= n) {
             return None;
         }
         Some(zip(index, &self.shape).fold(0, |acc, (i, shape)| acc * shape + i))
@@ -38,14 +64,15 @@ where
         Some(&mut self.data[i])
     }
 
-    fn get(&self, index: &[usize]) -> Option<T> {
+    /// The element at a full index, if it is within the array.
+    pub fn get(&self, index: &[usize]) -> Option<T> {
         let i = self.get_index(index)?;
-        Some(self.data[i])
+        Some(self.data[i].clone())
     }
 
     pub fn agrees<U>(&self, other: &GenericArray<U>) -> bool
     where
-        U: Copy + Debug,
+        U: Clone + Debug,
     {
         zip(&self.shape, &other.shape).all(|(a, w)| a == w)
     }
@@ -66,10 +93,37 @@ where
         &self.data
     }
 
+    /// Split the array into its cells of the given rank, returning the frame they are arranged in
+    /// alongside the cells themselves.
+    pub fn cells(self, rank: usize) -> (Vec<usize>, Vec<ArrayOrAtom<T>>) {
+        // Cells of rank 0 are atoms, even those of an array of rank 0
+        if rank >= self.rank() && rank > 0 {
+            return (vec![], vec![ArrayOrAtom::Array(self)]);
+        }
+        let (frame, shape) = self.shape.split_at(self.rank() - rank);
+        let (frame, shape) = (frame.to_vec(), shape.to_vec());
+        if rank == 0 {
+            return (
+                frame,
+                self.data.into_iter().map(ArrayOrAtom::Atom).collect(),
+            );
+        }
+        let size = shape.iter().product::<usize>();
+        let cells = (0..frame.iter().product::<usize>())
+            .map(|i| {
+                ArrayOrAtom::Array(GenericArray {
+                    shape: shape.clone(),
+                    data: self.data[i * size..(i + 1) * size].to_vec(),
+                })
+            })
+            .collect();
+        (frame, cells)
+    }
+
     pub fn map<F, U>(self, f: F) -> GenericArray<U>
     where
         F: Fn(T) -> U,
-        U: Copy + Debug,
+        U: Clone + Debug,
     {
         GenericArray {
             shape: self.shape,
@@ -77,27 +131,86 @@ where
         }
     }
 
+    /// Reverse the order of the items.
+    pub fn reverse(self) -> Self {
+        let size = self.shape.get(1..).unwrap_or_default().iter().product();
+        let data = if self.rank() == 0 || size == 0 {
+            self.data
+        } else {
+            self.data.chunks(size).rev().flatten().cloned().collect()
+        };
+        GenericArray {
+            shape: self.shape,
+            data,
+        }
+    }
+
+    /// Rotate along the leading axes by the given counts. With a fill element, shift instead,
+    /// filling the positions left behind.
+    pub fn rotate(self, counts: &[isize], fill: Option<T>) -> Self {
+        let data = (0..self.data.len())
+            .map(|i| {
+                let mut index = unravel(i, &self.shape);
+                for (j, (&c, &n)) in zip(&mut index, zip(counts, &self.shape)) {
+                    let k = *j as isize + c;
+                    match &fill {
+                        None => *j = k.rem_euclid(n as isize) as usize,
+                        Some(_) if (0..n as isize).contains(&k) => *j = k as usize,
+                        Some(f) => return f.clone(),
+                    }
+                }
+                self.get(&index).expect("rotated index is within the array")
+            })
+            .collect();
+        GenericArray {
+            shape: self.shape.clone(),
+            data,
+        }
+    }
+
+    /// Rearrange the axes. Each axis of the result runs along a group of axes of this array
+    /// together, so that a group of several axes selects a diagonal.
+    pub fn transpose(self, axes: &[Vec<usize>]) -> Self {
+        let shape = axes
+            .iter()
+            .map(|group| group.iter().map(|&k| self.shape[k]).min().unwrap_or(1))
+            .collect_vec();
+        let data = (0..shape.iter().product())
+            .map(|i| {
+                let mut index = vec![0; self.rank()];
+                for (group, j) in zip(axes, unravel(i, &shape)) {
+                    for &k in group {
+                        index[k] = j;
+                    }
+                }
+                self.get(&index)
+                    .expect("transposed index is within the array")
+            })
+            .collect();
+        GenericArray { shape, data }
+    }
+
     pub(crate) fn atom_map_right<F, U, V>(self, w: U, f: F) -> GenericArray<V>
     where
         F: Fn(T, U) -> V,
-        U: Copy + Debug,
-        V: Copy + Debug,
+        U: Clone + Debug,
+        V: Clone + Debug,
     {
         GenericArray {
             shape: self.shape,
-            data: self.data.into_iter().map(|a| f(a, w)).collect(),
+            data: self.data.into_iter().map(|a| f(a, w.clone())).collect(),
         }
     }
 
     pub(crate) fn atom_map_left<F, U, V>(self, a: U, f: F) -> GenericArray<V>
     where
         F: Fn(U, T) -> V,
-        U: Copy + Debug,
-        V: Copy + Debug,
+        U: Clone + Debug,
+        V: Clone + Debug,
     {
         GenericArray {
             shape: self.shape,
-            data: self.data.into_iter().map(|w| f(a, w)).collect(),
+            data: self.data.into_iter().map(|w| f(a.clone(), w)).collect(),
         }
     }
 
@@ -108,12 +221,12 @@ where
     ) -> anyhow::Result<GenericArray<V>>
     where
         F: Fn(T, U) -> V,
-        U: Copy + Debug,
-        V: Copy + Debug,
+        U: Clone + Debug,
+        V: Clone + Debug,
     {
         if !self.agrees(&other) {
             return Err(anyhow!(
-                "Incompatible arrays shapes for dyadic operation: {} and {}",
+                "Length error: frames {} and {} do not agree",
                 self.shape.iter().map(|w| w.to_string()).join(" "),
                 other.shape.iter().map(|w| w.to_string()).join(" ")
             ));
@@ -121,12 +234,12 @@ where
 
         let (shape, data) = match self.rank().cmp(&other.rank()) {
             Ordering::Less => {
-                let d = other.data.len() / self.data.len();
+                let d = other.data.len() / self.data.len().max(1);
                 let data = other
                     .data
                     .into_iter()
                     .enumerate()
-                    .map(|(i, w)| f(self.data[i / d], w))
+                    .map(|(i, w)| f(self.data[i / d].clone(), w))
                     .collect();
                 (other.shape, data)
             }
@@ -134,17 +247,17 @@ where
                 self.shape.clone(),
                 self.data
                     .into_iter()
-                    .zip(other.data.into_iter())
+                    .zip(other.data)
                     .map(|(a, w)| f(a, w))
                     .collect(),
             ),
             Ordering::Greater => {
-                let d = self.data.len() / other.data.len();
+                let d = self.data.len() / other.data.len().max(1);
                 let data = self
                     .data
                     .into_iter()
                     .enumerate()
-                    .map(|(i, a)| f(a, other.data[i / d]))
+                    .map(|(i, a)| f(a, other.data[i / d].clone()))
                     .collect();
                 (self.shape, data)
             }
@@ -154,35 +267,143 @@ where
     }
 }
 
-impl GenericArray<IntegerElt> {
-    pub fn iota(shape: &[IntegerElt]) -> Self {
-        GenericArray {
-            shape: shape.iter().map(|&w| w as usize).collect(),
-            data: (0..shape.iter().product::<IntegerElt>())
-                .map(|w| w as IntegerElt)
-                .collect(),
+impl<T> GenericArray<T>
+where
+    T: Clone + Debug + Fill,
+{
+    /// Assemble cells into a single array with the given frame. Cells of lower rank gain leading
+    /// axes of length 1, and all cells are padded with fill to the largest length along each axis.
+    pub fn from_cells(frame: &[usize], cells: Vec<ArrayOrAtom<T>>) -> anyhow::Result<Self> {
+        if frame.iter().product::<usize>() != cells.len() {
+            return Err(anyhow!("Cells do not fill a frame of {:?}", frame));
         }
+        let shapes = cells
+            .iter()
+            .map(|c| match c {
+                ArrayOrAtom::Array(a) => a.shape.clone(),
+                ArrayOrAtom::Atom(_) => vec![],
+            })
+            .collect_vec();
+        let rank = shapes.iter().map(Vec::len).max().unwrap_or(0);
+        let shapes = shapes
+            .into_iter()
+            .map(|s| [vec![1; rank - s.len()], s].concat())
+            .collect_vec();
+        let cell_shape = (0..rank)
+            .map(|i| shapes.iter().map(|s| s[i]).max().unwrap_or(0))
+            .collect_vec();
+
+        let shape = [frame, &cell_shape].concat();
+        let mut data = Vec::with_capacity(shape.iter().product());
+        for (cell, from) in zip(cells, shapes) {
+            match cell {
+                ArrayOrAtom::Atom(w) if cell_shape.iter().all(|&s| s == 1) => data.push(w),
+                ArrayOrAtom::Atom(w) => data.extend(pad(&from, &[w], &cell_shape)),
+                ArrayOrAtom::Array(w) if from == cell_shape => data.extend(w.data),
+                ArrayOrAtom::Array(w) => data.extend(pad(&from, &w.data, &cell_shape)),
+            }
+        }
+        Ok(GenericArray { shape, data })
+    }
+
+    /// Arrange the items into the given frame, reusing them cyclically. An empty array is
+    /// filled instead.
+    pub fn reshape(self, frame: &[usize]) -> anyhow::Result<Self> {
+        let item_shape = self.shape.get(1..).unwrap_or_default();
+        let shape = [frame, item_shape].concat();
+        let size = shape.iter().product();
+        let data = if self.data.is_empty() {
+            vec![T::fill(); size]
+        } else {
+            self.data.into_iter().cycle().take(size).collect()
+        };
+        Self::from_shape_and_data(shape, data)
     }
 }
 
-impl<T, S> TryFrom<&[S]> for GenericArray<T>
+impl<T> GenericArray<T>
 where
-    T: Copy + Debug + FromStr,
-    <T as FromStr>::Err: 'static + std::error::Error + Send + Sync,
-    S: AsRef<str> + Display,
+    T: Clone + Debug + Fill,
 {
-    type Error = anyhow::Error;
-
-    fn try_from(value: &[S]) -> anyhow::Result<Self> {
-        let shape = vec![value.len()];
-        let data = value
+    /// Select a window along the leading axes, given as a length and an offset into the array
+    /// for each. Positions outside the array are filled.
+    pub fn section(self, axes: &[(usize, isize)]) -> Self {
+        let shape = self
+            .shape
             .iter()
-            .map(|w| {
-                w.as_ref()
-                    .parse()
-                    .with_context(|| anyhow!("Failed to parse {w} as a number."))
+            .enumerate()
+            .map(|(k, &n)| axes.get(k).map_or(n, |&(length, _)| length))
+            .collect_vec();
+        let data = (0..shape.iter().product())
+            .map(|i| {
+                let offsets = axes
+                    .iter()
+                    .map(|&(_, offset)| offset)
+                    .chain(std::iter::repeat(0));
+                zip(unravel(i, &shape), offsets)
+                    .map(|(j, offset)| usize::try_from(j as isize + offset).ok())
+                    .collect::<Option<Vec<_>>>()
+                    .and_then(|index| self.get(&index))
+                    .unwrap_or_else(T::fill)
             })
-            .collect::<anyhow::Result<_>>()?;
-        Ok(Self { shape, data })
+            .collect();
+        GenericArray { shape, data }
+    }
+}
+
+/// The index in an array of the given shape of the `i`th element in order.
+fn unravel(mut i: usize, shape: &[usize]) -> Vec<usize> {
+    let mut index = vec![0; shape.len()];
+    for (j, &n) in zip(&mut index, shape).rev() {
+        *j = i % n;
+        i /= n;
+    }
+    index
+}
+
+/// Copy `data` of shape `from` into the corner of an array of the same rank with shape `to`,
+/// filling the remainder.
+fn pad<T>(from: &[usize], data: &[T], to: &[usize]) -> Vec<T>
+where
+    T: Clone + Fill,
+{
+    let mut out = vec![T::fill(); to.iter().product()];
+    for (i, w) in data.iter().enumerate() {
+        let (mut rem, mut index, mut stride) = (i, 0, 1);
+        for axis in (0..from.len()).rev() {
+            index += rem % from[axis] * stride;
+            rem /= from[axis];
+            stride *= to[axis];
+        }
+        out[index] = w.clone();
+    }
+    out
+}
+
+impl GenericArray<IntegerElt> {
+    /// The ravel indices of an array of shape `|shape`, reversed along each axis whose length
+    /// is negative.
+    pub fn iota(shape: &[IntegerElt]) -> Self {
+        let lengths = shape
+            .iter()
+            .map(|w| w.unsigned_abs() as usize)
+            .collect_vec();
+        let n = lengths.iter().product::<usize>();
+        let data = if shape.iter().all(|&w| w >= 0) {
+            (0..n).map(|i| i as IntegerElt).collect()
+        } else {
+            (0..n)
+                .map(|i| {
+                    let index = unravel(i, &lengths);
+                    zip(zip(index, &lengths), shape).fold(0, |k, ((j, &n), &w)| {
+                        k * n + if w < 0 { n - 1 - j } else { j }
+                    }) as IntegerElt
+                })
+                .collect()
+        };
+        GenericArray {
+            shape: lengths,
+            data,
+        }
     }
 }
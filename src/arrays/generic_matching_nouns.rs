@@ -2,11 +2,12 @@ use crate::arrays::array_or_atom::ArrayOrAtom;
 use crate::arrays::generic_array::GenericArray;
 use anyhow::{Context, Result};
 use std::fmt::Debug;
+use std::slice;
 
 #[derive(Debug, Clone)]
 pub enum GenericMatchingNouns<T>
 where
-    T: Copy + Debug,
+    T: Clone + Debug,
 {
     ArrArr(GenericArray<T>, GenericArray<T>),
     ArrAt(GenericArray<T>, T),
@@ -16,7 +17,7 @@ where
 
 impl<T> From<(ArrayOrAtom<T>, ArrayOrAtom<T>)> for GenericMatchingNouns<T>
 where
-    T: Copy + Debug,
+    T: Clone + Debug,
 {
     fn from(aw: (ArrayOrAtom<T>, ArrayOrAtom<T>)) -> Self {
         use crate::arrays::array_or_atom::ArrayOrAtom as AoA;
@@ -32,12 +33,12 @@ where
 
 impl<T> GenericMatchingNouns<T>
 where
-    T: Copy + Debug,
+    T: Clone + Debug,
 {
     pub fn dyad<F, U>(self, f: F) -> Result<ArrayOrAtom<U>>
     where
         F: Fn(T, T) -> U,
-        U: Copy + Debug,
+        U: Clone + Debug,
     {
         use GenericMatchingNouns::*;
         Ok(match self {
@@ -49,4 +50,15 @@ where
             AtAt(a, w) => ArrayOrAtom::Atom(f(a, w)),
         })
     }
+
+    /// The elements of each argument, for checking before the dyad is applied.
+    pub fn elements(&self) -> (&[T], &[T]) {
+        use GenericMatchingNouns::*;
+        match self {
+            ArrArr(a, w) => (a.raw_data(), w.raw_data()),
+            ArrAt(a, w) => (a.raw_data(), slice::from_ref(w)),
+            AtArr(a, w) => (slice::from_ref(a), w.raw_data()),
+            AtAt(a, w) => (slice::from_ref(a), slice::from_ref(w)),
+        }
+    }
 }
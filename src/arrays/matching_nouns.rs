@@ -1,14 +1,17 @@
 use crate::arrays::array_or_atom::ArrayOrAtom;
 use crate::arrays::generic_matching_nouns::GenericMatchingNouns;
 use crate::arrays::noun::Noun;
-use crate::arrays::{DecimalElt, IntegerElt};
+use crate::arrays::{ComplexElt, DecimalElt, ExtendedElt, IntegerElt, RationalElt};
 use std::fmt::Debug;
 
 #[derive(Debug, Clone)]
 pub enum MatchingNouns {
     Boolean(GenericMatchingNouns<bool>),
     Integer(GenericMatchingNouns<IntegerElt>),
+    Extended(GenericMatchingNouns<ExtendedElt>),
+    Rational(GenericMatchingNouns<RationalElt>),
     Decimal(GenericMatchingNouns<DecimalElt>),
+    Complex(GenericMatchingNouns<ComplexElt>),
 }
 
 impl From<GenericMatchingNouns<bool>> for MatchingNouns {
@@ -23,29 +26,131 @@ impl From<GenericMatchingNouns<IntegerElt>> for MatchingNouns {
     }
 }
 
+impl From<GenericMatchingNouns<ExtendedElt>> for MatchingNouns {
+    fn from(w: GenericMatchingNouns<ExtendedElt>) -> Self {
+        Self::Extended(w)
+    }
+}
+
+impl From<GenericMatchingNouns<RationalElt>> for MatchingNouns {
+    fn from(w: GenericMatchingNouns<RationalElt>) -> Self {
+        Self::Rational(w)
+    }
+}
+
 impl From<GenericMatchingNouns<DecimalElt>> for MatchingNouns {
     fn from(w: GenericMatchingNouns<DecimalElt>) -> Self {
         Self::Decimal(w)
     }
 }
 
+impl From<GenericMatchingNouns<ComplexElt>> for MatchingNouns {
+    fn from(w: GenericMatchingNouns<ComplexElt>) -> Self {
+        Self::Complex(w)
+    }
+}
+
 impl MatchingNouns {
-    pub fn dyad<FB, OB, FI, OI, FD, OD>(self, b: FB, i: FI, d: FD) -> anyhow::Result<Noun>
+    pub fn dyad<FB, OB, FI, OI, FX, OX, FR, OR, FD, OD, FJ, OJ>(
+        self,
+        b: FB,
+        i: FI,
+        x: FX,
+        r: FR,
+        d: FD,
+        j: FJ,
+    ) -> anyhow::Result<Noun>
     where
         FB: Fn(bool, bool) -> OB,
         FI: Fn(IntegerElt, IntegerElt) -> OI,
+        FX: Fn(ExtendedElt, ExtendedElt) -> OX,
+        FR: Fn(RationalElt, RationalElt) -> OR,
         FD: Fn(DecimalElt, DecimalElt) -> OD,
-        OB: Copy + Debug,
-        OI: Copy + Debug,
-        OD: Copy + Debug,
-        Noun: From<ArrayOrAtom<OB>> + From<ArrayOrAtom<OD>> + From<ArrayOrAtom<OI>>,
+        FJ: Fn(ComplexElt, ComplexElt) -> OJ,
+        OB: Clone + Debug,
+        OI: Clone + Debug,
+        OX: Clone + Debug,
+        OR: Clone + Debug,
+        OD: Clone + Debug,
+        OJ: Clone + Debug,
+        Noun: From<ArrayOrAtom<OB>>
+            + From<ArrayOrAtom<OI>>
+            + From<ArrayOrAtom<OX>>
+            + From<ArrayOrAtom<OR>>
+            + From<ArrayOrAtom<OD>>
+            + From<ArrayOrAtom<OJ>>,
     {
         use crate::arrays::matching_nouns::MatchingNouns as MN;
         use anyhow::Context;
         Ok(match self {
             MN::Boolean(nouns) => nouns.dyad(b).context("dyad failure")?.into(),
             MN::Integer(nouns) => nouns.dyad(i).context("dyad failure")?.into(),
+            MN::Extended(nouns) => nouns.dyad(x).context("dyad failure")?.into(),
+            MN::Rational(nouns) => nouns.dyad(r).context("dyad failure")?.into(),
             MN::Decimal(nouns) => nouns.dyad(d).context("dyad failure")?.into(),
+            MN::Complex(nouns) => nouns.dyad(j).context("dyad failure")?.into(),
+        })
+    }
+
+    /// As `dyad`, for verbs defined only on real numbers, giving a domain error for complex
+    /// arguments.
+    pub fn real_dyad<FB, OB, FI, OI, FX, OX, FR, OR, FD, OD>(
+        self,
+        b: FB,
+        i: FI,
+        x: FX,
+        r: FR,
+        d: FD,
+    ) -> anyhow::Result<Noun>
+    where
+        FB: Fn(bool, bool) -> OB,
+        FI: Fn(IntegerElt, IntegerElt) -> OI,
+        FX: Fn(ExtendedElt, ExtendedElt) -> OX,
+        FR: Fn(RationalElt, RationalElt) -> OR,
+        FD: Fn(DecimalElt, DecimalElt) -> OD,
+        OB: Clone + Debug,
+        OI: Clone + Debug,
+        OX: Clone + Debug,
+        OR: Clone + Debug,
+        OD: Clone + Debug,
+        Noun: From<ArrayOrAtom<OB>>
+            + From<ArrayOrAtom<OI>>
+            + From<ArrayOrAtom<OX>>
+            + From<ArrayOrAtom<OR>>
+            + From<ArrayOrAtom<OD>>,
+    {
+        use crate::arrays::matching_nouns::MatchingNouns as MN;
+        use anyhow::{anyhow, Context};
+        Ok(match self {
+            MN::Boolean(nouns) => nouns.dyad(b).context("dyad failure")?.into(),
+            MN::Integer(nouns) => nouns.dyad(i).context("dyad failure")?.into(),
+            MN::Extended(nouns) => nouns.dyad(x).context("dyad failure")?.into(),
+            MN::Rational(nouns) => nouns.dyad(r).context("dyad failure")?.into(),
+            MN::Decimal(nouns) => nouns.dyad(d).context("dyad failure")?.into(),
+            MN::Complex(_) => {
+                return Err(anyhow!("Domain error: complex arguments are not supported"))
+            }
+        })
+    }
+
+    /// Promote integer arguments to decimals if the checked operation `f` overflows for any pair
+    /// of their elements, so that the dyad is then run in decimals instead.
+    pub fn promote_on_overflow<F>(self, f: F) -> anyhow::Result<Self>
+    where
+        F: Fn(IntegerElt, IntegerElt) -> Option<IntegerElt>,
+    {
+        use crate::arrays::promote::Promote;
+        use anyhow::Context;
+        Ok(match self {
+            MatchingNouns::Integer(nouns) => {
+                let checked = nouns.clone().dyad(f).context("dyad failure")?;
+                if checked.elements().contains(&None) {
+                    MatchingNouns::Decimal(nouns.promote())
+                } else {
+                    MatchingNouns::Integer(nouns)
+                }
+            }
+            nouns => nouns,
         })
     }
 }
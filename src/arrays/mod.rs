@@ -1,6 +1,7 @@
 pub mod array;
 pub mod array_or_atom;
 pub mod atom;
+pub mod fill;
 pub mod generic_array;
 pub mod generic_matching_nouns;
 pub mod matching_nouns;
@@ -8,4 +9,7 @@ pub mod noun;
 pub mod promote;
 
 pub type IntegerElt = i64;
+pub type ExtendedElt = num::BigInt;
+pub type RationalElt = num::BigRational;
 pub type DecimalElt = f64;
+pub type ComplexElt = num::complex::Complex64;
@@ -1,47 +1,197 @@
 use crate::arrays::array::Array;
 use crate::arrays::array_or_atom::ArrayOrAtom;
 use crate::arrays::atom::Atom;
+use crate::arrays::fill::Fill;
 use crate::arrays::generic_array::GenericArray;
+use crate::arrays::generic_matching_nouns::GenericMatchingNouns;
 use crate::arrays::matching_nouns::MatchingNouns;
 use crate::arrays::promote::Promote;
-use crate::arrays::{DecimalElt, IntegerElt};
-use anyhow::anyhow;
+use crate::arrays::{ComplexElt, DecimalElt, ExtendedElt, IntegerElt, RationalElt};
+use anyhow::{anyhow, Context};
+use num::{One, ToPrimitive, Zero};
+use std::cmp::Ordering;
+use std::convert::identity;
 use std::fmt::Debug;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Noun {
     Array(Array),
     Atom(Atom),
 }
 
+/// The numeric types, in the order they are promoted through. Combining two numeric nouns
+/// promotes both to the later of their types.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Numeric {
+    Boolean,
+    Integer,
+    Extended,
+    Rational,
+    Decimal,
+    Complex,
+}
+
 impl Noun {
-    pub fn map<FB, FI, FD, V>(self, b: FB, i: FI, d: FD) -> ArrayOrAtom<V>
+    /// Apply one of six functions, chosen by type, to every element of a numeric noun.
+    pub fn map<FB, FI, FX, FR, FD, FJ, V>(
+        self,
+        b: FB,
+        i: FI,
+        x: FX,
+        r: FR,
+        d: FD,
+        j: FJ,
+    ) -> anyhow::Result<ArrayOrAtom<V>>
     where
         FB: Fn(bool) -> V,
         FI: Fn(IntegerElt) -> V,
+        FX: Fn(ExtendedElt) -> V,
+        FR: Fn(RationalElt) -> V,
         FD: Fn(DecimalElt) -> V,
-        V: Copy + Debug,
+        FJ: Fn(ComplexElt) -> V,
+        V: Clone + Debug,
     {
         use crate::arrays::array::Array as Arr;
         use crate::arrays::array_or_atom::ArrayOrAtom as AoA;
         use crate::arrays::atom::Atom as At;
         use Noun as N;
-        match self {
+        Ok(match self {
             N::Array(w) => AoA::Array(match w {
                 Arr::Boolean(w) => w.map(b),
                 Arr::Integer(w) => w.map(i),
+                Arr::Extended(w) => w.map(x),
+                Arr::Rational(w) => w.map(r),
                 Arr::Decimal(w) => w.map(d),
+                Arr::Complex(w) => w.map(j),
+                Arr::Character(_) | Arr::Boxed(_) => {
+                    return Err(anyhow!("Domain error: expected a number"))
+                }
             }),
             N::Atom(w) => AoA::Atom(match w {
                 At::Boolean(w) => b(w),
                 At::Integer(w) => i(w),
+                At::Extended(w) => x(w),
+                At::Rational(w) => r(w),
                 At::Decimal(w) => d(w),
+                At::Complex(w) => j(w),
+                At::Character(_) | At::Boxed(_) => {
+                    return Err(anyhow!("Domain error: expected a number"))
+                }
             }),
+        })
+    }
+
+    /// The noun as booleans, every element of which must be 0 or 1.
+    pub fn into_boolean(self) -> anyhow::Result<ArrayOrAtom<bool>> {
+        let boolean = self.clone().map(
+            |_| true,
+            |w| w == 0 || w == 1,
+            |w| w.is_zero() || w.is_one(),
+            |w| w.is_zero() || w.is_one(),
+            |w| w == 0.0 || w == 1.0,
+            |w| w.is_zero() || w.is_one(),
+        )?;
+        if boolean.elements().contains(&false) {
+            return Err(anyhow!("Domain error: expected only 0 and 1"));
         }
+        self.map(
+            |w| w,
+            |w| w != 0,
+            |w| !w.is_zero(),
+            |w| !w.is_zero(),
+            |w| w != 0.0,
+            |w| !w.is_zero(),
+        )
+    }
+
+    /// Apply one of six functions, chosen by type, to every element of a numeric noun. Unlike
+    /// `map`, each function may give a different result type.
+    pub fn monad<FB, OB, FI, OI, FX, OX, FR, OR, FD, OD, FJ, OJ>(
+        self,
+        b: FB,
+        i: FI,
+        x: FX,
+        r: FR,
+        d: FD,
+        j: FJ,
+    ) -> anyhow::Result<Noun>
+    where
+        FB: Fn(bool) -> OB,
+        FI: Fn(IntegerElt) -> OI,
+        FX: Fn(ExtendedElt) -> OX,
+        FR: Fn(RationalElt) -> OR,
+        FD: Fn(DecimalElt) -> OD,
+        FJ: Fn(ComplexElt) -> OJ,
+        OB: Clone + Debug,
+        OI: Clone + Debug,
+        OX: Clone + Debug,
+        OR: Clone + Debug,
+        OD: Clone + Debug,
+        OJ: Clone + Debug,
+        Noun: From<ArrayOrAtom<OB>>
+            + From<ArrayOrAtom<OI>>
+            + From<ArrayOrAtom<OX>>
+            + From<ArrayOrAtom<OR>>
+            + From<ArrayOrAtom<OD>>
+            + From<ArrayOrAtom<OJ>>,
+    {
+        use crate::arrays::array::Array as Arr;
+        use crate::arrays::array_or_atom::ArrayOrAtom as AoA;
+        use crate::arrays::atom::Atom as At;
+        use Noun as N;
+        Ok(match self {
+            N::Array(Arr::Boolean(w)) => AoA::Array(w.map(b)).into(),
+            N::Array(Arr::Integer(w)) => AoA::Array(w.map(i)).into(),
+            N::Array(Arr::Extended(w)) => AoA::Array(w.map(x)).into(),
+            N::Array(Arr::Rational(w)) => AoA::Array(w.map(r)).into(),
+            N::Array(Arr::Decimal(w)) => AoA::Array(w.map(d)).into(),
+            N::Array(Arr::Complex(w)) => AoA::Array(w.map(j)).into(),
+            N::Atom(At::Boolean(w)) => AoA::Atom(b(w)).into(),
+            N::Atom(At::Integer(w)) => AoA::Atom(i(w)).into(),
+            N::Atom(At::Extended(w)) => AoA::Atom(x(w)).into(),
+            N::Atom(At::Rational(w)) => AoA::Atom(r(w)).into(),
+            N::Atom(At::Decimal(w)) => AoA::Atom(d(w)).into(),
+            N::Atom(At::Complex(w)) => AoA::Atom(j(w)).into(),
+            N::Array(Arr::Character(_) | Arr::Boxed(_))
+            | N::Atom(At::Character(_) | At::Boxed(_)) => {
+                return Err(anyhow!("Domain error: expected a number"))
+            }
+        })
     }
 
-    pub fn into_boolean(self) -> ArrayOrAtom<bool> {
-        self.map(|w| w, |w| w != 0, |w| w != 0.0)
+    /// As `monad`, for verbs defined only on real numbers, giving a domain error for complex
+    /// arguments.
+    pub fn real_monad<FB, OB, FI, OI, FX, OX, FR, OR, FD, OD>(
+        self,
+        b: FB,
+        i: FI,
+        x: FX,
+        r: FR,
+        d: FD,
+    ) -> anyhow::Result<Noun>
+    where
+        FB: Fn(bool) -> OB,
+        FI: Fn(IntegerElt) -> OI,
+        FX: Fn(ExtendedElt) -> OX,
+        FR: Fn(RationalElt) -> OR,
+        FD: Fn(DecimalElt) -> OD,
+        OB: Clone + Debug,
+        OI: Clone + Debug,
+        OX: Clone + Debug,
+        OR: Clone + Debug,
+        OD: Clone + Debug,
+        Noun: From<ArrayOrAtom<OB>>
+            + From<ArrayOrAtom<OI>>
+            + From<ArrayOrAtom<OX>>
+            + From<ArrayOrAtom<OR>>
+            + From<ArrayOrAtom<OD>>,
+    {
+        if let Some(Numeric::Complex) = self.numeric() {
+            return Err(anyhow!("Domain error: complex arguments are not supported"));
+        }
+        self.monad(b, i, x, r, d, |_| -> OD {
+            unreachable!("complex arguments are rejected above")
+        })
     }
 
     pub fn shape(&self) -> Option<&[usize]> {
@@ -59,11 +209,547 @@ impl Noun {
             _ => None,
         }
     }
+
+    pub fn is_boxed(&self) -> bool {
+        matches!(
+            self,
+            Noun::Atom(Atom::Boxed(_)) | Noun::Array(Array::Boxed(_))
+        )
+    }
+
+    /// Put the whole noun into a single box.
+    pub fn enclose(self) -> Noun {
+        Noun::Atom(Atom::Boxed(Box::new(self)))
+    }
+
+    /// The contents of a boxed atom. Any other noun is returned unchanged.
+    pub fn unbox(self) -> Noun {
+        match self {
+            Noun::Atom(Atom::Boxed(w)) => *w,
+            w => w,
+        }
+    }
+
+    /// Read an atom or list as integers, as needed for counts, shapes and indices.
+    pub fn to_integers(&self) -> anyhow::Result<Vec<IntegerElt>> {
+        let small = |w: &ExtendedElt| {
+            w.to_i64()
+                .with_context(|| anyhow!("Domain error: {} is too large", w))
+        };
+        let ratio = |w: &RationalElt| {
+            if w.is_integer() {
+                small(w.numer())
+            } else {
+                Err(anyhow!("Domain error: {} is not an integer", w))
+            }
+        };
+        let real = |w: &ComplexElt| {
+            if w.im == 0.0 {
+                Ok(w.re)
+            } else {
+                Err(anyhow!("Domain error: {} is not an integer", w))
+            }
+        };
+        let whole = |w: DecimalElt| {
+            // Casting saturates, so check the range first; 2^63 itself is out of range
+            let range = IntegerElt::MIN as DecimalElt..IntegerElt::MAX as DecimalElt;
+            if w.fract() == 0.0 && !range.contains(&w) {
+                Err(anyhow!("Domain error: {} is too large", w))
+            } else if w.fract() == 0.0 {
+                Ok(w as IntegerElt)
+            } else {
+                Err(anyhow!("Domain error: {} is not an integer", w))
+            }
+        };
+        match self {
+            Noun::Atom(Atom::Boolean(w)) => Ok(vec![*w as IntegerElt]),
+            Noun::Atom(Atom::Integer(w)) => Ok(vec![*w]),
+            Noun::Atom(Atom::Extended(w)) => Ok(vec![small(w)?]),
+            Noun::Atom(Atom::Rational(w)) => Ok(vec![ratio(w)?]),
+            Noun::Atom(Atom::Decimal(w)) => Ok(vec![whole(*w)?]),
+            Noun::Atom(Atom::Complex(w)) => Ok(vec![whole(real(w)?)?]),
+            Noun::Array(w) if w.rank() > 1 => Err(anyhow!("Rank error: expected a list")),
+            Noun::Array(Array::Boolean(w)) => {
+                Ok(w.raw_data().iter().map(|&w| w as IntegerElt).collect())
+            }
+            Noun::Array(Array::Integer(w)) => Ok(w.raw_data().to_vec()),
+            Noun::Array(Array::Extended(w)) => w.raw_data().iter().map(small).collect(),
+            Noun::Array(Array::Rational(w)) => w.raw_data().iter().map(ratio).collect(),
+            Noun::Array(Array::Decimal(w)) => w.raw_data().iter().map(|&w| whole(w)).collect(),
+            Noun::Array(Array::Complex(w)) => {
+                w.raw_data().iter().map(|w| whole(real(w)?)).collect()
+            }
+            Noun::Atom(Atom::Character(_)) | Noun::Array(Array::Character(_)) => {
+                Err(anyhow!("Domain error: expected integers, got characters"))
+            }
+            Noun::Atom(Atom::Boxed(_)) | Noun::Array(Array::Boxed(_)) => {
+                Err(anyhow!("Domain error: expected integers, got boxes"))
+            }
+        }
+    }
+
+    /// Read a single integer, as needed for counts and lengths.
+    pub fn to_integer(&self) -> anyhow::Result<IntegerElt> {
+        match self.to_integers()?.as_slice() {
+            [w] => Ok(*w),
+            _ => Err(anyhow!("Length error: expected a single integer")),
+        }
+    }
+
+    /// Read a single real number, as needed for tolerances.
+    pub fn to_decimal(&self) -> anyhow::Result<DecimalElt> {
+        match self.clone().promote_to(Numeric::Decimal) {
+            Noun::Atom(Atom::Decimal(w)) => Ok(w),
+            w => Err(anyhow!("Domain error: expected a real number, got {:?}", w)),
+        }
+    }
+
+    /// Demote decimals to integers if every element is whole and in range, and rationals to
+    /// extended integers if every element is whole. Operations on exact arguments, like `6 % 3`,
+    /// then keep an exact result.
+    pub fn demote(self) -> Noun {
+        let integral = |w: DecimalElt| {
+            w.fract() == 0.0
+                && w >= IntegerElt::MIN as DecimalElt
+                && w < IntegerElt::MAX as DecimalElt
+        };
+        match self {
+            Noun::Atom(Atom::Decimal(w)) if integral(w) => {
+                Noun::Atom(Atom::Integer(w as IntegerElt))
+            }
+            Noun::Array(Array::Decimal(w)) if w.raw_data().iter().all(|&w| integral(w)) => {
+                Noun::Array(Array::Integer(w.map(|w| w as IntegerElt)))
+            }
+            Noun::Atom(Atom::Rational(w)) if w.is_integer() => {
+                Noun::Atom(Atom::Extended(w.to_integer()))
+            }
+            Noun::Array(Array::Rational(w)) if w.raw_data().iter().all(RationalElt::is_integer) => {
+                Noun::Array(Array::Extended(w.map(|w| w.to_integer())))
+            }
+            w => w,
+        }
+    }
+
+    /// Read an atom or list of characters as a string, as needed for lists of names.
+    pub fn to_text(&self) -> anyhow::Result<String> {
+        match self {
+            Noun::Atom(Atom::Character(w)) => Ok(w.to_string()),
+            Noun::Array(Array::Character(w)) if w.rank() == 1 => Ok(w.raw_data().iter().collect()),
+            w => Err(anyhow!(
+                "Domain error: expected a character list, got {:?}",
+                w
+            )),
+        }
+    }
+
+    /// Split the noun into its cells of the given rank, returning the frame they are arranged in
+    /// alongside the cells themselves. An atom is its own single cell.
+    pub fn cells(self, rank: usize) -> (Vec<usize>, Vec<Noun>) {
+        use crate::arrays::array::Array as Arr;
+        use Noun as N;
+        fn nouns<T>((frame, cells): (Vec<usize>, Vec<ArrayOrAtom<T>>)) -> (Vec<usize>, Vec<Noun>)
+        where
+            T: Clone + Debug,
+            Noun: From<ArrayOrAtom<T>>,
+        {
+            (frame, cells.into_iter().map(Noun::from).collect())
+        }
+        match self {
+            N::Array(Arr::Boolean(w)) => nouns(w.cells(rank)),
+            N::Array(Arr::Integer(w)) => nouns(w.cells(rank)),
+            N::Array(Arr::Extended(w)) => nouns(w.cells(rank)),
+            N::Array(Arr::Rational(w)) => nouns(w.cells(rank)),
+            N::Array(Arr::Decimal(w)) => nouns(w.cells(rank)),
+            N::Array(Arr::Complex(w)) => nouns(w.cells(rank)),
+            N::Array(Arr::Character(w)) => nouns(w.cells(rank)),
+            N::Array(Arr::Boxed(w)) => nouns(w.cells(rank)),
+            N::Atom(w) => (vec![], vec![N::Atom(w)]),
+        }
+    }
+
+    /// The noun as an array, with an atom becoming an array of rank 0.
+    pub fn into_array(self) -> Array {
+        use crate::arrays::atom::Atom as At;
+        match self {
+            Noun::Array(w) => w,
+            Noun::Atom(At::Boolean(w)) => GenericArray::scalar(w).into(),
+            Noun::Atom(At::Integer(w)) => GenericArray::scalar(w).into(),
+            Noun::Atom(At::Extended(w)) => GenericArray::scalar(w).into(),
+            Noun::Atom(At::Rational(w)) => GenericArray::scalar(w).into(),
+            Noun::Atom(At::Decimal(w)) => GenericArray::scalar(w).into(),
+            Noun::Atom(At::Complex(w)) => GenericArray::scalar(w).into(),
+            Noun::Atom(At::Character(w)) => GenericArray::scalar(w).into(),
+            Noun::Atom(At::Boxed(w)) => GenericArray::scalar(w).into(),
+        }
+    }
+
+    /// Arrange the items of the noun into the given frame, reusing them cyclically.
+    pub fn reshape(self, frame: &[usize]) -> anyhow::Result<Noun> {
+        use crate::arrays::array::Array as Arr;
+        fn reshaped<T>(w: GenericArray<T>, frame: &[usize]) -> anyhow::Result<Noun>
+        where
+            T: Clone + Debug + Fill,
+            Noun: From<ArrayOrAtom<T>>,
+        {
+            let w = w.reshape(frame)?;
+            // A result of rank 0 is an atom
+            Ok(Noun::from(match w.get(&[]) {
+                Some(w) => ArrayOrAtom::Atom(w),
+                None => ArrayOrAtom::Array(w),
+            }))
+        }
+        match self.into_array() {
+            Arr::Boolean(w) => reshaped(w, frame),
+            Arr::Integer(w) => reshaped(w, frame),
+            Arr::Extended(w) => reshaped(w, frame),
+            Arr::Rational(w) => reshaped(w, frame),
+            Arr::Decimal(w) => reshaped(w, frame),
+            Arr::Complex(w) => reshaped(w, frame),
+            Arr::Character(w) => reshaped(w, frame),
+            Arr::Boxed(w) => reshaped(w, frame),
+        }
+    }
+
+    /// The same elements arranged in a new shape, which must hold exactly as many.
+    pub fn with_shape(self, shape: Vec<usize>) -> anyhow::Result<Noun> {
+        use crate::arrays::array::Array as Arr;
+        Ok(Noun::Array(match self.into_array() {
+            Arr::Boolean(w) => w.with_shape(shape)?.into(),
+            Arr::Integer(w) => w.with_shape(shape)?.into(),
+            Arr::Extended(w) => w.with_shape(shape)?.into(),
+            Arr::Rational(w) => w.with_shape(shape)?.into(),
+            Arr::Decimal(w) => w.with_shape(shape)?.into(),
+            Arr::Complex(w) => w.with_shape(shape)?.into(),
+            Arr::Character(w) => w.with_shape(shape)?.into(),
+            Arr::Boxed(w) => w.with_shape(shape)?.into(),
+        }))
+    }
+
+    /// Select a window along the leading axes, given as a length and an offset for each, filling
+    /// positions outside the noun.
+    pub fn section(self, axes: &[(usize, isize)]) -> Noun {
+        use crate::arrays::array::Array as Arr;
+        Noun::Array(match self.into_array() {
+            Arr::Boolean(w) => w.section(axes).into(),
+            Arr::Integer(w) => w.section(axes).into(),
+            Arr::Extended(w) => w.section(axes).into(),
+            Arr::Rational(w) => w.section(axes).into(),
+            Arr::Decimal(w) => w.section(axes).into(),
+            Arr::Complex(w) => w.section(axes).into(),
+            Arr::Character(w) => w.section(axes).into(),
+            Arr::Boxed(w) => w.section(axes).into(),
+        })
+    }
+
+    /// Reverse the order of the items. An atom is unchanged.
+    pub fn reverse(self) -> Noun {
+        use crate::arrays::array::Array as Arr;
+        match self {
+            Noun::Array(Arr::Boolean(w)) => w.reverse().into(),
+            Noun::Array(Arr::Integer(w)) => w.reverse().into(),
+            Noun::Array(Arr::Extended(w)) => w.reverse().into(),
+            Noun::Array(Arr::Rational(w)) => w.reverse().into(),
+            Noun::Array(Arr::Decimal(w)) => w.reverse().into(),
+            Noun::Array(Arr::Complex(w)) => w.reverse().into(),
+            Noun::Array(Arr::Character(w)) => w.reverse().into(),
+            Noun::Array(Arr::Boxed(w)) => w.reverse().into(),
+            w @ Noun::Atom(_) => w,
+        }
+    }
+
+    /// Rotate along the leading axes by the given counts, or shift in `fill` if given. An atom
+    /// is unchanged.
+    pub fn rotate(self, counts: &[isize], fill: Option<Noun>) -> anyhow::Result<Noun> {
+        use crate::arrays::array::Array as Arr;
+        fn fill_as<T>(fill: Option<Noun>) -> anyhow::Result<Option<T>>
+        where
+            T: Clone + Debug,
+            ArrayOrAtom<T>: TryFrom<Noun, Error = anyhow::Error>,
+        {
+            match fill.map(ArrayOrAtom::try_from).transpose()? {
+                Some(ArrayOrAtom::Array(_)) => Err(anyhow!("Rank error: the fill must be an atom")),
+                Some(ArrayOrAtom::Atom(f)) => Ok(Some(f)),
+                None => Ok(None),
+            }
+        }
+        let (w, fill) = match fill {
+            Some(f) => match f.numeric().max(self.numeric()) {
+                Some(to) => (self.promote_to(to), Some(f.promote_to(to))),
+                None => (self, Some(f)),
+            },
+            None => (self, None),
+        };
+        Ok(match w {
+            Noun::Array(Arr::Boolean(w)) => w.rotate(counts, fill_as(fill)?).into(),
+            Noun::Array(Arr::Integer(w)) => w.rotate(counts, fill_as(fill)?).into(),
+            Noun::Array(Arr::Extended(w)) => w.rotate(counts, fill_as(fill)?).into(),
+            Noun::Array(Arr::Rational(w)) => w.rotate(counts, fill_as(fill)?).into(),
+            Noun::Array(Arr::Decimal(w)) => w.rotate(counts, fill_as(fill)?).into(),
+            Noun::Array(Arr::Complex(w)) => w.rotate(counts, fill_as(fill)?).into(),
+            Noun::Array(Arr::Character(w)) => w.rotate(counts, fill_as(fill)?).into(),
+            Noun::Array(Arr::Boxed(w)) => w.rotate(counts, fill_as(fill)?).into(),
+            w @ Noun::Atom(_) => w,
+        })
+    }
+
+    /// Rearrange the axes, with each axis of the result running along a group of axes of the
+    /// noun. An atom is unchanged.
+    pub fn transpose(self, axes: &[Vec<usize>]) -> Noun {
+        use crate::arrays::array::Array as Arr;
+        match self {
+            Noun::Array(Arr::Boolean(w)) => w.transpose(axes).into(),
+            Noun::Array(Arr::Integer(w)) => w.transpose(axes).into(),
+            Noun::Array(Arr::Extended(w)) => w.transpose(axes).into(),
+            Noun::Array(Arr::Rational(w)) => w.transpose(axes).into(),
+            Noun::Array(Arr::Decimal(w)) => w.transpose(axes).into(),
+            Noun::Array(Arr::Complex(w)) => w.transpose(axes).into(),
+            Noun::Array(Arr::Character(w)) => w.transpose(axes).into(),
+            Noun::Array(Arr::Boxed(w)) => w.transpose(axes).into(),
+            w @ Noun::Atom(_) => w,
+        }
+    }
+
+    /// The total array ordering, treating atoms as arrays of rank 0.
+    pub fn total_cmp(&self, other: &Noun) -> Ordering {
+        self.clone()
+            .into_array()
+            .total_cmp(&other.clone().into_array())
+    }
+
+    /// Split the noun along its leading axis. An atom, or an array of rank 0, is its own single
+    /// item.
+    pub fn items(self) -> Vec<Noun> {
+        match self.rank() {
+            Some(rank) if rank > 0 => self.cells(rank - 1).1,
+            _ => self.cells(0).1,
+        }
+    }
+
+    /// Assemble cells into a noun with the given frame, promoting them to a common type and
+    /// padding them to a common shape.
+    pub fn from_cells(frame: &[usize], mut cells: Vec<Noun>) -> anyhow::Result<Noun> {
+        use crate::arrays::array::Array as Arr;
+        use crate::arrays::atom::Atom as At;
+        use Noun as N;
+        if frame.is_empty() && cells.len() == 1 {
+            return Ok(cells.remove(0));
+        }
+        if let Some(to) = cells.iter().filter_map(Noun::numeric).max() {
+            cells = cells.into_iter().map(|c| c.promote_to(to)).collect();
+        }
+        match cells.first() {
+            Some(N::Atom(At::Boolean(_)) | N::Array(Arr::Boolean(_))) => {
+                Ok(GenericArray::<bool>::from_cells(frame, try_collect(cells)?)?.into())
+            }
+            Some(N::Atom(At::Integer(_)) | N::Array(Arr::Integer(_))) => {
+                Ok(GenericArray::<IntegerElt>::from_cells(frame, try_collect(cells)?)?.into())
+            }
+            Some(N::Atom(At::Extended(_)) | N::Array(Arr::Extended(_))) => {
+                Ok(GenericArray::<ExtendedElt>::from_cells(frame, try_collect(cells)?)?.into())
+            }
+            Some(N::Atom(At::Rational(_)) | N::Array(Arr::Rational(_))) => {
+                Ok(GenericArray::<RationalElt>::from_cells(frame, try_collect(cells)?)?.into())
+            }
+            Some(N::Atom(At::Decimal(_)) | N::Array(Arr::Decimal(_))) => {
+                Ok(GenericArray::<DecimalElt>::from_cells(frame, try_collect(cells)?)?.into())
+            }
+            Some(N::Atom(At::Complex(_)) | N::Array(Arr::Complex(_))) => {
+                Ok(GenericArray::<ComplexElt>::from_cells(frame, try_collect(cells)?)?.into())
+            }
+            Some(N::Atom(At::Character(_)) | N::Array(Arr::Character(_))) => {
+                Ok(GenericArray::<char>::from_cells(frame, try_collect(cells)?)?.into())
+            }
+            Some(N::Atom(At::Boxed(_)) | N::Array(Arr::Boxed(_))) => {
+                Ok(GenericArray::<Box<Noun>>::from_cells(frame, try_collect(cells)?)?.into())
+            }
+            None => Ok(GenericArray::<IntegerElt>::from_cells(frame, vec![])?.into()),
+        }
+    }
+
+    pub fn numeric(&self) -> Option<Numeric> {
+        use crate::arrays::array::Array as Arr;
+        use crate::arrays::atom::Atom as At;
+        use Noun as N;
+        match self {
+            N::Atom(At::Boolean(_)) | N::Array(Arr::Boolean(_)) => Some(Numeric::Boolean),
+            N::Atom(At::Integer(_)) | N::Array(Arr::Integer(_)) => Some(Numeric::Integer),
+            N::Atom(At::Extended(_)) | N::Array(Arr::Extended(_)) => Some(Numeric::Extended),
+            N::Atom(At::Rational(_)) | N::Array(Arr::Rational(_)) => Some(Numeric::Rational),
+            N::Atom(At::Decimal(_)) | N::Array(Arr::Decimal(_)) => Some(Numeric::Decimal),
+            N::Atom(At::Complex(_)) | N::Array(Arr::Complex(_)) => Some(Numeric::Complex),
+            _ => None,
+        }
+    }
+
+    /// Promote a numeric noun straight to the numeric type `to`. Nouns of a later type, and
+    /// non-numeric nouns, are left alone.
+    pub fn promote_to(self, to: Numeric) -> Noun {
+        if !matches!(self.numeric(), Some(t) if t < to) {
+            return self;
+        }
+        let w = match to {
+            Numeric::Boolean => return self,
+            Numeric::Integer => self.monad(
+                Promote::<IntegerElt>::promote,
+                identity,
+                identity,
+                identity,
+                identity,
+                identity,
+            ),
+            Numeric::Extended => self.monad(
+                Promote::<ExtendedElt>::promote,
+                Promote::<ExtendedElt>::promote,
+                identity,
+                identity,
+                identity,
+                identity,
+            ),
+            Numeric::Rational => self.monad(
+                Promote::<RationalElt>::promote,
+                Promote::<RationalElt>::promote,
+                Promote::<RationalElt>::promote,
+                identity,
+                identity,
+                identity,
+            ),
+            Numeric::Decimal => self.monad(
+                Promote::<DecimalElt>::promote,
+                Promote::<DecimalElt>::promote,
+                Promote::<DecimalElt>::promote,
+                Promote::<DecimalElt>::promote,
+                identity,
+                identity,
+            ),
+            Numeric::Complex => self.monad(
+                Promote::<ComplexElt>::promote,
+                Promote::<ComplexElt>::promote,
+                Promote::<ComplexElt>::promote,
+                Promote::<ComplexElt>::promote,
+                Promote::<ComplexElt>::promote,
+                identity,
+            ),
+        };
+        w.unwrap_or_else(|_| unreachable!("non-numeric nouns are returned above"))
+    }
+
+    /// Assemble items of identical type and shape into a noun with one more axis.
+    pub fn from_items(items: Vec<Noun>) -> anyhow::Result<Noun> {
+        Noun::from_cells(&[items.len()], items)
+    }
+}
+
+fn try_collect<T>(items: Vec<Noun>) -> anyhow::Result<Vec<ArrayOrAtom<T>>>
+where
+    T: Clone + Debug,
+    ArrayOrAtom<T>: TryFrom<Noun, Error = anyhow::Error>,
+{
+    items
+        .into_iter()
+        .map(ArrayOrAtom::try_from)
+        .collect::<anyhow::Result<_>>()
+        .context("Domain error: cannot combine nouns of different types")
+}
+
+impl TryFrom<Noun> for ArrayOrAtom<bool> {
+    type Error = anyhow::Error;
+
+    fn try_from(w: Noun) -> anyhow::Result<Self> {
+        match w {
+            Noun::Array(Array::Boolean(w)) => Ok(ArrayOrAtom::Array(w)),
+            Noun::Atom(Atom::Boolean(w)) => Ok(ArrayOrAtom::Atom(w)),
+            w => Err(anyhow!("Expected a boolean noun, got {:?}", w)),
+        }
+    }
+}
+
+impl TryFrom<Noun> for ArrayOrAtom<IntegerElt> {
+    type Error = anyhow::Error;
+
+    fn try_from(w: Noun) -> anyhow::Result<Self> {
+        match w {
+            Noun::Array(Array::Integer(w)) => Ok(ArrayOrAtom::Array(w)),
+            Noun::Atom(Atom::Integer(w)) => Ok(ArrayOrAtom::Atom(w)),
+            w => Err(anyhow!("Expected an integer noun, got {:?}", w)),
+        }
+    }
+}
+
+impl TryFrom<Noun> for ArrayOrAtom<ExtendedElt> {
+    type Error = anyhow::Error;
+
+    fn try_from(w: Noun) -> anyhow::Result<Self> {
+        match w {
+            Noun::Array(Array::Extended(w)) => Ok(ArrayOrAtom::Array(w)),
+            Noun::Atom(Atom::Extended(w)) => Ok(ArrayOrAtom::Atom(w)),
+            w => Err(anyhow!("Expected an extended noun, got {:?}", w)),
+        }
+    }
+}
+
+impl TryFrom<Noun> for ArrayOrAtom<RationalElt> {
+    type Error = anyhow::Error;
+
+    fn try_from(w: Noun) -> anyhow::Result<Self> {
+        match w {
+            Noun::Array(Array::Rational(w)) => Ok(ArrayOrAtom::Array(w)),
+            Noun::Atom(Atom::Rational(w)) => Ok(ArrayOrAtom::Atom(w)),
+            w => Err(anyhow!("Expected a rational noun, got {:?}", w)),
+        }
+    }
+}
+
+impl TryFrom<Noun> for ArrayOrAtom<DecimalElt> {
+    type Error = anyhow::Error;
+
+    fn try_from(w: Noun) -> anyhow::Result<Self> {
+        match w {
+            Noun::Array(Array::Decimal(w)) => Ok(ArrayOrAtom::Array(w)),
+            Noun::Atom(Atom::Decimal(w)) => Ok(ArrayOrAtom::Atom(w)),
+            w => Err(anyhow!("Expected a decimal noun, got {:?}", w)),
+        }
+    }
+}
+
+impl TryFrom<Noun> for ArrayOrAtom<ComplexElt> {
+    type Error = anyhow::Error;
+
+    fn try_from(w: Noun) -> anyhow::Result<Self> {
+        match w {
+            Noun::Array(Array::Complex(w)) => Ok(ArrayOrAtom::Array(w)),
+            Noun::Atom(Atom::Complex(w)) => Ok(ArrayOrAtom::Atom(w)),
+            w => Err(anyhow!("Expected a complex noun, got {:?}", w)),
+        }
+    }
+}
+
+impl TryFrom<Noun> for ArrayOrAtom<char> {
+    type Error = anyhow::Error;
+
+    fn try_from(w: Noun) -> anyhow::Result<Self> {
+        match w {
+            Noun::Array(Array::Character(w)) => Ok(ArrayOrAtom::Array(w)),
+            Noun::Atom(Atom::Character(w)) => Ok(ArrayOrAtom::Atom(w)),
+            w => Err(anyhow!("Expected a character noun, got {:?}", w)),
+        }
+    }
+}
+
+impl TryFrom<Noun> for ArrayOrAtom<Box<Noun>> {
+    type Error = anyhow::Error;
+
+    fn try_from(w: Noun) -> anyhow::Result<Self> {
+        match w {
+            Noun::Array(Array::Boxed(w)) => Ok(ArrayOrAtom::Array(w)),
+            Noun::Atom(Atom::Boxed(w)) => Ok(ArrayOrAtom::Atom(w)),
+            w => Err(anyhow!("Expected a boxed noun, got {:?}", w)),
+        }
+    }
 }
 
 impl<T> From<ArrayOrAtom<T>> for Noun
 where
-    T: Copy + Debug,
+    T: Clone + Debug,
     Atom: From<T>,
     Array: From<GenericArray<T>>,
 {
@@ -77,7 +763,7 @@ where
 
 impl<T> From<GenericArray<T>> for Noun
 where
-    T: Copy + Debug,
+    T: Clone + Debug,
     Array: From<GenericArray<T>>,
 {
     fn from(w: GenericArray<T>) -> Self {
@@ -87,7 +773,7 @@ where
 
 impl<T> From<T> for Noun
 where
-    T: Copy + Debug,
+    T: Clone + Debug,
     Atom: From<T>,
 {
     fn from(w: T) -> Self {
@@ -102,126 +788,35 @@ impl From<Array> for Noun {
 }
 
 impl Noun {
+    /// Promote two numeric nouns to their common type, ready to apply a dyad elementwise.
     pub fn try_promote_pair(a: Noun, w: Noun) -> anyhow::Result<MatchingNouns> {
-        use crate::arrays::array::Array as Arr;
-        use crate::arrays::atom::Atom as At;
-        use crate::arrays::generic_matching_nouns::GenericMatchingNouns::*;
         use crate::arrays::matching_nouns::MatchingNouns as MN;
-        use Noun as N;
-        match (a, w) {
-            // Array - Array
-            // Identical cases
-            (N::Array(Arr::Boolean(a)), N::Array(Arr::Boolean(w))) => Ok(MN::Boolean(ArrArr(a, w))),
-            (N::Array(Arr::Integer(a)), N::Array(Arr::Integer(w))) => Ok(MN::Integer(ArrArr(a, w))),
-            (N::Array(Arr::Decimal(a)), N::Array(Arr::Decimal(w))) => Ok(MN::Decimal(ArrArr(a, w))),
-            // Boolean left
-            (N::Array(Arr::Boolean(a)), N::Array(Arr::Integer(w))) => {
-                Ok(MN::Integer(ArrArr(a.promote(), w)))
+        let to = match (a.numeric(), w.numeric()) {
+            (Some(ta), Some(tw)) => ta.max(tw),
+            _ => {
+                return Err(anyhow!(
+                    "Incompatible types for promotion: {:?} and {:?}",
+                    a,
+                    w
+                ))
             }
-            (N::Array(Arr::Boolean(a)), N::Array(Arr::Decimal(w))) => {
-                Ok(MN::Decimal(ArrArr(a.promote(), w)))
-            }
-            // Integer left
-            (N::Array(Arr::Integer(a)), N::Array(Arr::Boolean(w))) => {
-                Ok(MN::Integer(ArrArr(a, w.promote())))
-            }
-            (N::Array(Arr::Integer(a)), N::Array(Arr::Decimal(w))) => {
-                Ok(MN::Decimal(ArrArr(a.promote(), w)))
-            }
-            // Decimal left
-            (N::Array(Arr::Decimal(a)), N::Array(Arr::Boolean(w))) => {
-                Ok(MN::Decimal(ArrArr(a, w.promote())))
-            }
-            (N::Array(Arr::Decimal(a)), N::Array(Arr::Integer(w))) => {
-                Ok(MN::Decimal(ArrArr(a, w.promote())))
-            }
-
-            // Array - Atom
-            // Identical cases
-            (N::Array(Arr::Boolean(a)), N::Atom(At::Boolean(w))) => Ok(MN::Boolean(ArrAt(a, w))),
-            (N::Array(Arr::Integer(a)), N::Atom(At::Integer(w))) => Ok(MN::Integer(ArrAt(a, w))),
-            (N::Array(Arr::Decimal(a)), N::Atom(At::Decimal(w))) => Ok(MN::Decimal(ArrAt(a, w))),
-            // Boolean left
-            (N::Array(Arr::Boolean(a)), N::Atom(At::Integer(w))) => {
-                Ok(MN::Integer(ArrAt(a.promote(), w)))
-            }
-            (N::Array(Arr::Boolean(a)), N::Atom(At::Decimal(w))) => {
-                Ok(MN::Decimal(ArrAt(a.promote(), w)))
-            }
-            // Integer left
-            (N::Array(Arr::Integer(a)), N::Atom(At::Boolean(w))) => {
-                Ok(MN::Integer(ArrAt(a, w.promote())))
-            }
-            (N::Array(Arr::Integer(a)), N::Atom(At::Decimal(w))) => {
-                Ok(MN::Decimal(ArrAt(a.promote(), w)))
-            }
-            // Decimal left
-            (N::Array(Arr::Decimal(a)), N::Atom(At::Boolean(w))) => {
-                Ok(MN::Decimal(ArrAt(a, w.promote())))
-            }
-            (N::Array(Arr::Decimal(a)), N::Atom(At::Integer(w))) => {
-                Ok(MN::Decimal(ArrAt(a, w.promote())))
-            }
-
-            // Atom - Array
-            // Identical cases
-            (N::Atom(At::Boolean(a)), N::Array(Arr::Boolean(w))) => Ok(MN::Boolean(AtArr(a, w))),
-            (N::Atom(At::Integer(a)), N::Array(Arr::Integer(w))) => Ok(MN::Integer(AtArr(a, w))),
-            (N::Atom(At::Decimal(a)), N::Array(Arr::Decimal(w))) => Ok(MN::Decimal(AtArr(a, w))),
-            // Boolean left
-            (N::Atom(At::Boolean(a)), N::Array(Arr::Integer(w))) => {
-                Ok(MN::Integer(AtArr(a.promote(), w)))
-            }
-            (N::Atom(At::Boolean(a)), N::Array(Arr::Decimal(w))) => {
-                Ok(MN::Decimal(AtArr(a.promote(), w)))
-            }
-            // Integer left
-            (N::Atom(At::Integer(a)), N::Array(Arr::Boolean(w))) => {
-                Ok(MN::Integer(AtArr(a, w.promote())))
-            }
-            (N::Atom(At::Integer(a)), N::Array(Arr::Decimal(w))) => {
-                Ok(MN::Decimal(AtArr(a.promote(), w)))
-            }
-            // Decimal left
-            (N::Atom(At::Decimal(a)), N::Array(Arr::Boolean(w))) => {
-                Ok(MN::Decimal(AtArr(a, w.promote())))
-            }
-            (N::Atom(At::Decimal(a)), N::Array(Arr::Integer(w))) => {
-                Ok(MN::Decimal(AtArr(a, w.promote())))
-            }
-
-            // Atom - Atom
-            // Identical cases
-            (N::Atom(At::Boolean(a)), N::Atom(At::Boolean(w))) => Ok(MN::Boolean(AtAt(a, w))),
-            (N::Atom(At::Integer(a)), N::Atom(At::Integer(w))) => Ok(MN::Integer(AtAt(a, w))),
-            (N::Atom(At::Decimal(a)), N::Atom(At::Decimal(w))) => Ok(MN::Decimal(AtAt(a, w))),
-            // Boolean left
-            (N::Atom(At::Boolean(a)), N::Atom(At::Integer(w))) => {
-                Ok(MN::Integer(AtAt(a.promote(), w)))
-            }
-            (N::Atom(At::Boolean(a)), N::Atom(At::Decimal(w))) => {
-                Ok(MN::Decimal(AtAt(a.promote(), w)))
-            }
-            // Integer left
-            (N::Atom(At::Integer(a)), N::Atom(At::Boolean(w))) => {
-                Ok(MN::Integer(AtAt(a, w.promote())))
-            }
-            (N::Atom(At::Integer(a)), N::Atom(At::Decimal(w))) => {
-                Ok(MN::Decimal(AtAt(a.promote(), w)))
-            }
-            // Decimal left
-            (N::Atom(At::Decimal(a)), N::Atom(At::Boolean(w))) => {
-                Ok(MN::Decimal(AtAt(a, w.promote())))
-            }
-            (N::Atom(At::Decimal(a)), N::Atom(At::Integer(w))) => {
-                Ok(MN::Decimal(AtAt(a, w.promote())))
-            }
-            #[allow(unreachable_patterns)]
-            (a, w) => Err(anyhow!(
-                "Incompatible types for promotion: {:?} and {:?}",
-                a,
-                w
-            )),
-        }
+        };
+        let (a, w) = (a.promote_to(to), w.promote_to(to));
+        Ok(match to {
+            Numeric::Boolean => MN::Boolean(pair(a, w)?),
+            Numeric::Integer => MN::Integer(pair(a, w)?),
+            Numeric::Extended => MN::Extended(pair(a, w)?),
+            Numeric::Rational => MN::Rational(pair(a, w)?),
+            Numeric::Decimal => MN::Decimal(pair(a, w)?),
+            Numeric::Complex => MN::Complex(pair(a, w)?),
+        })
     }
 }
+
+fn pair<T>(a: Noun, w: Noun) -> anyhow::Result<GenericMatchingNouns<T>>
+where
+    T: Clone + Debug,
+    ArrayOrAtom<T>: TryFrom<Noun, Error = anyhow::Error>,
+{
+    Ok((ArrayOrAtom::try_from(a)?, ArrayOrAtom::try_from(w)?).into())
+}
@@ -1,7 +1,8 @@
 use crate::arrays::array_or_atom::ArrayOrAtom;
 use crate::arrays::generic_array::GenericArray;
 use crate::arrays::generic_matching_nouns::GenericMatchingNouns;
-use crate::arrays::{DecimalElt, IntegerElt};
+use crate::arrays::{ComplexElt, DecimalElt, ExtendedElt, IntegerElt, RationalElt};
+use num::ToPrimitive;
 use std::fmt::Debug;
 
 pub trait Promote<T> {
@@ -22,6 +23,27 @@ impl Promote<DecimalElt> for bool {
     }
 }
 
+impl Promote<ExtendedElt> for bool {
+    #[inline]
+    fn promote(self) -> ExtendedElt {
+        ExtendedElt::from(self as IntegerElt)
+    }
+}
+
+impl Promote<RationalElt> for bool {
+    #[inline]
+    fn promote(self) -> RationalElt {
+        RationalElt::from_integer(self.promote())
+    }
+}
+
+impl Promote<ComplexElt> for bool {
+    #[inline]
+    fn promote(self) -> ComplexElt {
+        ComplexElt::new(self.promote(), 0.0)
+    }
+}
+
 impl Promote<DecimalElt> for IntegerElt {
     #[inline]
     fn promote(self) -> DecimalElt {
@@ -29,10 +51,73 @@ impl Promote<DecimalElt> for IntegerElt {
     }
 }
 
+impl Promote<ExtendedElt> for IntegerElt {
+    #[inline]
+    fn promote(self) -> ExtendedElt {
+        ExtendedElt::from(self)
+    }
+}
+
+impl Promote<RationalElt> for IntegerElt {
+    #[inline]
+    fn promote(self) -> RationalElt {
+        RationalElt::from_integer(self.promote())
+    }
+}
+
+impl Promote<ComplexElt> for IntegerElt {
+    #[inline]
+    fn promote(self) -> ComplexElt {
+        ComplexElt::new(self.promote(), 0.0)
+    }
+}
+
+impl Promote<DecimalElt> for ExtendedElt {
+    #[inline]
+    fn promote(self) -> DecimalElt {
+        self.to_f64().unwrap_or(DecimalElt::NAN)
+    }
+}
+
+impl Promote<RationalElt> for ExtendedElt {
+    #[inline]
+    fn promote(self) -> RationalElt {
+        RationalElt::from_integer(self)
+    }
+}
+
+impl Promote<ComplexElt> for ExtendedElt {
+    #[inline]
+    fn promote(self) -> ComplexElt {
+        ComplexElt::new(self.promote(), 0.0)
+    }
+}
+
+impl Promote<DecimalElt> for RationalElt {
+    #[inline]
+    fn promote(self) -> DecimalElt {
+        self.to_f64().unwrap_or(DecimalElt::NAN)
+    }
+}
+
+impl Promote<ComplexElt> for RationalElt {
+    #[inline]
+    fn promote(self) -> ComplexElt {
+        ComplexElt::new(self.promote(), 0.0)
+    }
+}
+
+impl Promote<ComplexElt> for DecimalElt {
+    #[inline]
+    fn promote(self) -> ComplexElt {
+        ComplexElt::new(self, 0.0)
+    }
+}
+
 impl<T, U> Promote<GenericArray<U>> for GenericArray<T>
 where
-    T: Copy + Debug + Promote<U>,
-    U: Copy + Debug,
+    T: Clone + Debug + Promote<U>,
+    U: Clone + Debug,
 {
     #[inline]
     fn promote(self) -> GenericArray<U> {
@@ -42,8 +127,8 @@ where
 
 impl<T, U> Promote<ArrayOrAtom<U>> for ArrayOrAtom<T>
 where
-    T: Copy + Debug + Promote<U>,
-    U: Copy + Debug,
+    T: Clone + Debug + Promote<U>,
+    U: Clone + Debug,
 {
     #[inline]
     fn promote(self) -> ArrayOrAtom<U> {
@@ -57,8 +142,8 @@ where
 
 impl<T, U> Promote<GenericMatchingNouns<U>> for GenericMatchingNouns<T>
 where
-    T: Copy + Debug + Promote<U>,
-    U: Copy + Debug,
+    T: Clone + Debug + Promote<U>,
+    U: Clone + Debug,
 {
     #[inline]
     fn promote(self) -> GenericMatchingNouns<U> {
@@ -1,85 +1,419 @@
-use crate::arrays::array::Array;
 use crate::arrays::atom::Atom;
 use crate::arrays::generic_array::GenericArray;
 use crate::arrays::noun::Noun;
-use crate::arrays::IntegerElt;
+use crate::arrays::{ComplexElt, DecimalElt, ExtendedElt, IntegerElt, RationalElt};
 use crate::lexer::{lex, Token};
-use crate::primitives::{DYADS, MONADS};
+use crate::primitives::{ADVERBS, CONJUNCTIONS, DYADS, MONADS};
+use crate::verb::{Operand, Verb};
 use anyhow::Result;
 use anyhow::{anyhow, Context};
+use num::Zero;
 use std::collections::HashMap;
 use std::io::{stdin, stdout, Write};
 
-#[allow(unused)]
 #[derive(Debug, Clone)]
 pub enum Variable {
     Noun(Noun),
-    Verb(String),
+    Verb(Verb),
 }
 
-pub fn interpret(mut csl: Vec<Token>, env: &mut HashMap<String, Variable>) -> Result<Option<Noun>> {
-    if csl.is_empty() {
-        return Ok(None);
+fn assign(env: &mut HashMap<String, Variable>, name: String, value: Variable) -> Result<()> {
+    if !is_valid_name(&name) {
+        return Err(anyhow!("Invalid name {}", name));
     }
+    env.insert(name, value);
+    Ok(())
+}
 
-    if let Some(Token::Eol) = csl.last() {
-        csl.pop();
-    }
+fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
 
-    // For now, we will only consider the case where the rightmost value is a numeric noun
-    // Safe to unwrap because we know csl is nonempty
-    let mut right = get_noun(env, csl.pop().unwrap()).with_context(|| "No rightmost noun")?;
+/// A single entry on the parser stack.
+#[derive(Debug, Clone)]
+enum Word {
+    /// Marks the left edge of the sentence.
+    Mark,
+    LeftParen,
+    RightParen,
+    /// `=.` or `=:`. There are no explicit definitions to hold local names yet, so both assign
+    /// globally.
+    Copula,
+    Name(String),
+    Noun(Noun),
+    Verb(Verb),
+    Adverb(&'static str),
+    Conjunction(&'static str),
+}
 
-    while let Some(token) = csl.pop() {
+impl Word {
+    fn from_token(token: Token) -> Result<Self> {
         match token {
-            Token::Operator(o) => {
-                // Look ahead to see if we're in the monadic or dyadic case
-                if let Some(Token::Number(_) | Token::Identifier(_)) = csl.last() {
-                    // We can unwrap here because we know the next thing is a noun
-                    match get_noun(env, csl.pop().unwrap()) {
-                        Some(n) => {
-                            right = DYADS
-                                .get(&o)
-                                .with_context(|| anyhow!("Unknown dyad {}", &o))?(
-                                n, right
-                            )?;
-                        }
-                        None => return Err(anyhow!("Failed to retrieve noun for left operator")),
+            Token::Identifier(name) => Ok(Word::Name(name)),
+            Token::Number(v) => get_noun(v).map(Word::Noun),
+            Token::Operator(o) => match o.as_str() {
+                "(" => Ok(Word::LeftParen),
+                ")" => Ok(Word::RightParen),
+                "=." | "=:" => Ok(Word::Copula),
+                o => {
+                    if let Some((&a, _)) = ADVERBS.get_entry(o) {
+                        Ok(Word::Adverb(a))
+                    } else if let Some((&c, _)) = CONJUNCTIONS.get_entry(o) {
+                        Ok(Word::Conjunction(c))
+                    } else if let Some(&v) = MONADS.get_key(o).or_else(|| DYADS.get_key(o)) {
+                        Ok(Word::Verb(Verb::Primitive(v)))
+                    } else {
+                        Err(anyhow!("Unknown primitive {}", o))
                     }
-                } else {
-                    right = MONADS
-                        .get(&o)
-                        .with_context(|| anyhow!("Unknown monad {}", &o))?(
-                        right
-                    )?;
                 }
-            }
-            t => return Err(anyhow!("Nonsensical token {t:?}")),
+            },
+            Token::StringLiteral(s) => Ok(Word::Noun(get_string(&s))),
+            t => Err(anyhow!("Nonsensical token {t:?}")),
+        }
+    }
+
+    fn is_edge(&self) -> bool {
+        matches!(self, Word::Mark | Word::LeftParen | Word::Copula)
+    }
+
+    fn is_edge_avn(&self) -> bool {
+        self.is_edge() || matches!(self, Word::Adverb(_) | Word::Verb(_) | Word::Noun(_))
+    }
+
+    fn is_cavn(&self) -> bool {
+        matches!(
+            self,
+            Word::Conjunction(_) | Word::Adverb(_) | Word::Verb(_) | Word::Noun(_)
+        )
+    }
+
+    fn into_operand(self) -> Result<Operand> {
+        match self {
+            Word::Noun(n) => Ok(Operand::Noun(n)),
+            Word::Verb(v) => Ok(Operand::Verb(v)),
+            w => Err(anyhow!("Expected a noun or verb, got {:?}", w)),
+        }
+    }
+
+    fn into_noun(self) -> Result<Noun> {
+        match self {
+            Word::Noun(n) => Ok(n),
+            w => Err(anyhow!("Expected a noun, got {:?}", w)),
         }
     }
 
-    Ok(Some(right))
+    fn into_verb(self) -> Result<Verb> {
+        match self {
+            Word::Verb(v) => Ok(v),
+            w => Err(anyhow!("Expected a verb, got {:?}", w)),
+        }
+    }
 }
 
-fn get_noun(env: &mut HashMap<String, Variable>, tok: Token) -> Option<Noun> {
-    match tok {
-        Token::Identifier(name) => {
-            if let Some(Variable::Noun(n)) = env.get(&name) {
-                Some(n.clone())
-            } else {
-                None
+/// The rules of the parse table, in order of precedence.
+#[derive(Debug, Copy, Clone)]
+enum Rule {
+    Monad0,
+    Monad1,
+    Dyad,
+    Adverb,
+    Conjunction,
+    Fork,
+    Hook,
+    Is,
+    Paren,
+}
+
+impl Rule {
+    /// Find the first rule matching the top four words of the stack, along with the span of
+    /// stack positions it consumes.
+    fn find(stack: &[Word]) -> Option<(Rule, usize, usize)> {
+        use Word as W;
+        let peek = |i: usize| stack.len().checked_sub(i + 1).map(|j| &stack[j]);
+        match (peek(0), peek(1), peek(2), peek(3)) {
+            (Some(e), Some(W::Verb(_)), Some(W::Noun(_)), _) if e.is_edge() => {
+                Some((Rule::Monad0, 1, 2))
+            }
+            (Some(e), Some(W::Verb(_)), Some(W::Verb(_)), Some(W::Noun(_))) if e.is_edge_avn() => {
+                Some((Rule::Monad1, 2, 3))
+            }
+            (Some(e), Some(W::Noun(_)), Some(W::Verb(_)), Some(W::Noun(_))) if e.is_edge_avn() => {
+                Some((Rule::Dyad, 1, 3))
+            }
+            (Some(e), Some(W::Verb(_) | W::Noun(_)), Some(W::Adverb(_)), _) if e.is_edge_avn() => {
+                Some((Rule::Adverb, 1, 2))
+            }
+            (
+                Some(e),
+                Some(W::Verb(_) | W::Noun(_)),
+                Some(W::Conjunction(_)),
+                Some(W::Verb(_) | W::Noun(_)),
+            ) if e.is_edge_avn() => Some((Rule::Conjunction, 1, 3)),
+            (Some(e), Some(W::Verb(_) | W::Noun(_)), Some(W::Verb(_)), Some(W::Verb(_)))
+                if e.is_edge_avn() =>
+            {
+                Some((Rule::Fork, 1, 3))
+            }
+            (Some(e), Some(W::Verb(_)), Some(W::Verb(_)), _) if e.is_edge() => {
+                Some((Rule::Hook, 1, 2))
+            }
+            (Some(W::Name(_) | W::Noun(_)), Some(W::Copula), Some(w), _) if w.is_cavn() => {
+                Some((Rule::Is, 0, 2))
             }
+            (Some(W::LeftParen), Some(w), Some(W::RightParen), _) if w.is_cavn() => {
+                Some((Rule::Paren, 0, 2))
+            }
+            _ => None,
+        }
+    }
+
+    /// Reduce the words matched by this rule, given in left-to-right order, to a single word.
+    fn apply(self, words: Vec<Word>, env: &mut HashMap<String, Variable>) -> Result<Word> {
+        let mut words = words.into_iter();
+        let mut next = || words.next().context("Parser stack underflow");
+        Ok(match self {
+            Rule::Monad0 | Rule::Monad1 => {
+                let v = next()?.into_verb()?;
+                Word::Noun(v.monad(next()?.into_noun()?)?)
+            }
+            Rule::Dyad => {
+                let a = next()?.into_noun()?;
+                let v = next()?.into_verb()?;
+                Word::Noun(v.dyad(a, next()?.into_noun()?)?)
+            }
+            Rule::Adverb => {
+                let u = next()?.into_operand()?;
+                match next()? {
+                    Word::Adverb(a) => Word::Verb(Verb::Adverb(a, Box::new(u))),
+                    w => return Err(anyhow!("Expected an adverb, got {:?}", w)),
+                }
+            }
+            Rule::Conjunction => {
+                let u = next()?.into_operand()?;
+                let c = match next()? {
+                    Word::Conjunction(c) => c,
+                    w => return Err(anyhow!("Expected a conjunction, got {:?}", w)),
+                };
+                let v = next()?.into_operand()?;
+                Word::Verb(Verb::Conjunction(c, Box::new(u), Box::new(v)))
+            }
+            Rule::Fork => {
+                let f = next()?.into_operand()?;
+                let g = next()?.into_verb()?;
+                let h = next()?.into_verb()?;
+                match f {
+                    Operand::Verb(Verb::Primitive("[:")) => {
+                        Word::Verb(Verb::CappedFork(Box::new(g), Box::new(h)))
+                    }
+                    f => Word::Verb(Verb::Fork(Box::new(f), Box::new(g), Box::new(h))),
+                }
+            }
+            Rule::Hook => {
+                let f = next()?.into_verb()?;
+                let g = next()?.into_verb()?;
+                Word::Verb(Verb::Hook(Box::new(f), Box::new(g)))
+            }
+            Rule::Is => {
+                let target = next()?;
+                match next()? {
+                    Word::Copula => (),
+                    w => return Err(anyhow!("Expected a copula, got {:?}", w)),
+                };
+                let value = next()?;
+                match (target, &value) {
+                    (Word::Name(name), Word::Noun(n)) => {
+                        assign(env, name, Variable::Noun(n.clone()))?
+                    }
+                    (Word::Name(name), Word::Verb(v)) => {
+                        assign(env, name, Variable::Verb(v.clone()))?
+                    }
+                    // A character list names several targets at once
+                    (Word::Noun(list), Word::Noun(n)) => {
+                        assign_multiple(env, &list.to_text()?, n.clone())?
+                    }
+                    (t, v) => return Err(anyhow!("Cannot assign {:?} to {:?}", v, t)),
+                }
+                value
+            }
+            Rule::Paren => {
+                next()?;
+                next()?
+            }
+        })
+    }
+}
+
+/// Assign each item of a noun to the corresponding name of a space-separated list.
+/// A single name receives the whole noun, and an atom is given to every name.
+fn assign_multiple(env: &mut HashMap<String, Variable>, list: &str, n: Noun) -> Result<()> {
+    let targets = list.split_whitespace().collect::<Vec<_>>();
+    if let [name] = targets.as_slice() {
+        return assign(env, name.to_string(), Variable::Noun(n));
+    }
+
+    let items = match n {
+        Noun::Atom(_) => vec![n; targets.len()],
+        n => n.items(),
+    };
+    if items.len() != targets.len() {
+        return Err(anyhow!(
+            "Length error: {} names but {} values",
+            targets.len(),
+            items.len()
+        ));
+    }
+    for (name, item) in targets.into_iter().zip(items) {
+        assign(env, name.to_string(), Variable::Noun(item))?;
+    }
+    Ok(())
+}
+
+/// Look up a name as it moves onto the stack, unless it is about to be assigned to.
+fn resolve(word: Word, top: Option<&Word>, env: &HashMap<String, Variable>) -> Result<Word> {
+    match (word, top) {
+        (Word::Name(name), Some(Word::Copula)) => Ok(Word::Name(name)),
+        (Word::Name(name), _) => match env.get(&name) {
+            Some(Variable::Noun(n)) => Ok(Word::Noun(n.clone())),
+            Some(Variable::Verb(v)) => Ok(Word::Verb(v.clone())),
+            None => Err(anyhow!("Undefined name {}", name)),
+        },
+        (w, _) => Ok(w),
+    }
+}
+
+pub fn interpret(csl: Vec<Token>, env: &mut HashMap<String, Variable>) -> Result<Option<Variable>> {
+    let mut queue = vec![Word::Mark];
+    for token in csl {
+        if token != Token::Eol {
+            queue.push(Word::from_token(token)?);
+        }
+    }
+
+    let mut stack: Vec<Word> = Vec::with_capacity(queue.len());
+    let mut assigned = false;
+    loop {
+        if let Some((rule, start, end)) = Rule::find(&stack) {
+            // Stack positions count down from the top, which is the end of the vector
+            let top = stack.len() - 1;
+            let mut words = stack.split_off(top - end);
+            let above = words.split_off(end - start + 1);
+            words.reverse();
+            stack.push(rule.apply(words, env)?);
+            stack.extend(above);
+            assigned = matches!(rule, Rule::Is);
+        } else if let Some(word) = queue.pop() {
+            let word = resolve(word, stack.last(), env)?;
+            stack.push(word);
+        } else {
+            break;
+        }
+    }
+
+    match stack.as_slice() {
+        [Word::Mark] => Ok(None),
+        [_, Word::Mark] if assigned => Ok(None),
+        [Word::Noun(n), Word::Mark] => Ok(Some(Variable::Noun(n.clone()))),
+        [Word::Verb(v), Word::Mark] => Ok(Some(Variable::Verb(v.clone()))),
+        _ => Err(anyhow!("Syntax error: {:?}", stack)),
+    }
+}
+
+/// Strip the surrounding quotes from a string literal and collapse doubled quotes.
+fn unquote(s: &str) -> String {
+    s[1..s.len() - 1].replace("''", "'")
+}
+
+/// Build a character noun from a string literal. A single character is an atom, and anything
+/// else a list.
+fn get_string(literal: &str) -> Noun {
+    let chars = unquote(literal).chars().collect::<Vec<_>>();
+    match *chars.as_slice() {
+        [c] => Noun::from(c),
+        _ => Noun::from(GenericArray::new(chars)),
+    }
+}
+
+/// Parse a single number in J syntax: `_` is both the negative sign and infinity, `_.` is NaN,
+/// exponents are written with `e`, a trailing `x` makes an extended integer, `r` separates the
+/// numerator and denominator of a rational, and `j` the real and imaginary parts of a complex.
+fn parse_number(word: &str) -> Result<Atom> {
+    let (negative, digits) = match word.strip_prefix('_') {
+        Some(digits) => (true, digits),
+        None => (false, word),
+    };
+    let sign = if negative { -1.0 } else { 1.0 };
+    let atom = match digits {
+        "" if negative => Atom::Decimal(DecimalElt::INFINITY),
+        "_" if negative => Atom::Decimal(DecimalElt::NEG_INFINITY),
+        "." if negative => Atom::Decimal(DecimalElt::NAN),
+        d if !d.starts_with(|c: char| c.is_ascii_digit()) => {
+            return Err(anyhow!("Failed to parse {} as a number.", word))
         }
-        Token::Number(v) => {
-            if v.len() > 1 {
-                Some(Noun::Array(Array::Integer(
-                    GenericArray::<IntegerElt>::try_from(v.as_slice()).ok()?,
-                )))
-            } else {
-                Some(Noun::Atom(Atom::Integer(v[0].parse().ok()?)))
+        d if d.contains('j') => {
+            let parse = |d: &str| {
+                d.replace('_', "-")
+                    .parse::<DecimalElt>()
+                    .with_context(|| anyhow!("Failed to parse {} as a number.", word))
+            };
+            let (re, im) = d.split_once('j').unwrap_or_default();
+            let (re, im) = (parse(re)?, parse(im)?);
+            Atom::Complex(ComplexElt::new(if negative { -re } else { re }, im))
+        }
+        d if d.contains('r') => {
+            let parse = |d: &str| {
+                d.replace('_', "-")
+                    .parse::<ExtendedElt>()
+                    .with_context(|| anyhow!("Failed to parse {} as a number.", word))
+            };
+            let (n, q) = d.split_once('r').unwrap_or_default();
+            let (n, q) = (parse(n)?, parse(q)?);
+            if q.is_zero() {
+                return Err(anyhow!("Domain error: {} has a zero denominator", word));
             }
+            Atom::Rational(RationalElt::new(if negative { -n } else { n }, q))
+        }
+        d if d.ends_with('x') => {
+            let x = d[..d.len() - 1]
+                .parse::<ExtendedElt>()
+                .with_context(|| anyhow!("Failed to parse {} as a number.", word))?;
+            Atom::Extended(if negative { -x } else { x })
         }
-        _ => None,
+        d if d.contains(['.', 'e']) => {
+            let d = d
+                .replace('_', "-")
+                .parse::<DecimalElt>()
+                .with_context(|| anyhow!("Failed to parse {} as a number.", word))?;
+            Atom::Decimal(sign * d)
+        }
+        // J stores 0 and 1 as booleans, so literals take the narrowest type that holds them
+        "0" | "1" if !negative => Atom::Boolean(digits == "1"),
+        d => match d.parse::<IntegerElt>() {
+            Ok(i) if negative => Atom::Integer(-i),
+            Ok(i) => Atom::Integer(i),
+            // Integers too large for IntegerElt become decimals
+            Err(_) => Atom::Decimal(
+                sign * d
+                    .parse::<DecimalElt>()
+                    .with_context(|| anyhow!("Failed to parse {} as a number.", word))?,
+            ),
+        },
+    };
+    Ok(atom)
+}
+
+/// Build a noun from a numeric literal, promoting every element to the narrowest type that holds
+/// them all: boolean, then integer, then decimal.
+fn get_noun(words: Vec<String>) -> Result<Noun> {
+    let mut atoms = words
+        .iter()
+        .map(|w| parse_number(w).map(Noun::Atom))
+        .collect::<Result<Vec<_>>>()?;
+    if atoms.len() == 1 {
+        Ok(atoms.remove(0))
+    } else {
+        Noun::from_items(atoms)
     }
 }
 
@@ -94,10 +428,151 @@ pub fn repl() -> Result<()> {
         match lex(&buffer) {
             Ok(csl) => match interpret(csl, &mut env) {
                 Ok(None) => (),
-                Ok(Some(n)) => println!("{n:?}"),
+                Ok(Some(Variable::Noun(n))) => println!("{n:?}"),
+                Ok(Some(Variable::Verb(v))) => println!("{v:?}"),
                 Err(e) => eprintln!("{e:?}"),
             },
             Err(e) => eprintln!("{e:?}"),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrays::generic_array::GenericArray;
+    use crate::testing::list;
+
+    #[test]
+    fn negative_and_infinite_numbers() {
+        assert_eq!(parse_number("_3").unwrap(), Atom::Integer(-3));
+        assert_eq!(
+            parse_number("_").unwrap(),
+            Atom::Decimal(DecimalElt::INFINITY)
+        );
+        assert_eq!(
+            parse_number("__").unwrap(),
+            Atom::Decimal(DecimalElt::NEG_INFINITY)
+        );
+        assert!(matches!(parse_number("_.").unwrap(), Atom::Decimal(d) if d.is_nan()));
+    }
+
+    #[test]
+    fn decimal_numbers() {
+        assert_eq!(parse_number("1.5").unwrap(), Atom::Decimal(1.5));
+        assert_eq!(parse_number("1e6").unwrap(), Atom::Decimal(1e6));
+        assert_eq!(parse_number("_2.5e_1").unwrap(), Atom::Decimal(-0.25));
+        assert!(parse_number("1.2.3").is_err());
+    }
+
+    #[test]
+    fn extended_numbers() {
+        let big = "12345678901234567890123".parse::<ExtendedElt>().unwrap();
+        let result = parse_number("12345678901234567890123x").unwrap();
+        assert_eq!(result, Atom::Extended(big));
+        let result = parse_number("_5x").unwrap();
+        assert_eq!(result, Atom::Extended(ExtendedElt::from(-5)));
+        assert!(parse_number("1.5x").is_err());
+    }
+
+    #[test]
+    fn rational_numbers() {
+        let ratio = |n: i64, q: i64| RationalElt::new(n.into(), q.into());
+        assert_eq!(parse_number("1r3").unwrap(), Atom::Rational(ratio(1, 3)));
+        assert_eq!(parse_number("_4r6").unwrap(), Atom::Rational(ratio(-2, 3)));
+        assert_eq!(parse_number("3r_4").unwrap(), Atom::Rational(ratio(-3, 4)));
+        assert!(parse_number("1r0").is_err());
+    }
+
+    #[test]
+    fn mixed_literals_are_promoted() {
+        let words = ["1", "2.5", "_3"].map(String::from).to_vec();
+        let expected = Noun::from(GenericArray::new(vec![1.0, 2.5, -3.0]));
+        assert_eq!(get_noun(words).unwrap(), expected);
+    }
+
+    fn eval_in(sentence: &str, env: &mut HashMap<String, Variable>) -> Result<Noun> {
+        match interpret(lex(&format!("{sentence}\n"))?, env)? {
+            Some(Variable::Noun(n)) => Ok(n),
+            v => Err(anyhow!("Expected a noun, got {:?}", v)),
+        }
+    }
+
+    fn eval(sentence: &str) -> Result<Noun> {
+        eval_in(sentence, &mut HashMap::new())
+    }
+
+    #[test]
+    fn monad() {
+        assert_eq!(eval("- 5").unwrap(), Noun::from(-5 as IntegerElt));
+        assert_eq!(eval("- - 5").unwrap(), Noun::from(5 as IntegerElt));
+    }
+
+    #[test]
+    fn dyad_is_right_to_left() {
+        assert_eq!(eval("2 * 3 + 4").unwrap(), Noun::from(14 as IntegerElt));
+        assert_eq!(eval("1 2 + - 3").unwrap(), list(&[-2, -1]));
+    }
+
+    #[test]
+    fn adverb() {
+        assert_eq!(eval("+/ 1 2 3").unwrap(), Noun::from(6 as IntegerElt));
+    }
+
+    #[test]
+    fn conjunction() {
+        assert_eq!(eval("1&+ 2").unwrap(), Noun::from(3 as IntegerElt));
+        assert_eq!(eval("-@+ 3").unwrap(), Noun::from(-3 as IntegerElt));
+    }
+
+    #[test]
+    fn fork() {
+        assert_eq!(eval("(+/ % #) 1 2 3 4").unwrap(), Noun::from(2.5));
+        assert_eq!(eval("2 (+ * -) 1").unwrap(), Noun::from(3 as IntegerElt));
+    }
+
+    #[test]
+    fn hook() {
+        assert_eq!(eval("(* -) 3").unwrap(), Noun::from(-9 as IntegerElt));
+        assert_eq!(eval("2 (+ -) 3").unwrap(), Noun::from(-1 as IntegerElt));
+    }
+
+    #[test]
+    fn is() {
+        let mut env = HashMap::new();
+        assert!(interpret(lex("a =: 2\n").unwrap(), &mut env)
+            .unwrap()
+            .is_none());
+        assert_eq!(
+            eval_in("a + 1", &mut env).unwrap(),
+            Noun::from(3 as IntegerElt)
+        );
+        interpret(lex("inc =: 1&+\n").unwrap(), &mut env).unwrap();
+        assert_eq!(
+            eval_in("inc a", &mut env).unwrap(),
+            Noun::from(3 as IntegerElt)
+        );
+        interpret(lex("'b c' =. 4 5\n").unwrap(), &mut env).unwrap();
+        assert_eq!(eval_in("b , c", &mut env).unwrap(), list(&[4, 5]));
+    }
+
+    #[test]
+    fn paren() {
+        assert_eq!(eval("(1 + 2) * 3").unwrap(), Noun::from(9 as IntegerElt));
+        assert_eq!(eval("((2))").unwrap(), Noun::from(2 as IntegerElt));
+    }
+
+    #[test]
+    fn syntax_error() {
+        assert!(eval("1 +").is_err());
+        assert!(eval("undefined 1").is_err());
+    }
+
+    #[test]
+    fn whole_decimals_as_lengths() {
+        assert_eq!(eval("2e0 $ 7").unwrap(), list(&[7, 7]));
+        assert!(eval("1e30 $ 1").is_err());
+        assert!(eval("_1e30 {. 1").is_err());
+        assert!(eval("1.5 $ 1").is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use anyhow::Context;
+use anyhow::{anyhow, Context};
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 enum InputClass {
@@ -23,7 +23,7 @@ impl fsm_lexer::InputClassifier for InputClass {
             '0'..='9' | '_' => Digit,
             '.' => Dot,
             ':' => Colon,
-            '"' => Quote,
+            '\'' => Quote,
             '\n' => LF,
             _ => Other,
         }
@@ -159,8 +159,68 @@ impl fsm_lexer::Tokeniser<LexerState> for Token {
     }
 }
 
+/// Check that the parentheses outside of string literals are balanced, pointing at the column of
+/// the first one that is not.
+fn check_parens(input: &str) -> anyhow::Result<()> {
+    let line = input.trim_end();
+    let unbalanced = |paren, column: usize| {
+        anyhow!(
+            "Unbalanced {} at column {}\n{}\n{}^",
+            paren,
+            column + 1,
+            line,
+            " ".repeat(column)
+        )
+    };
+
+    let mut open = vec![];
+    let mut quoted = false;
+    for (column, c) in line.chars().enumerate() {
+        match c {
+            '\'' => quoted = !quoted,
+            '(' if !quoted => open.push(column),
+            ')' if !quoted => {
+                open.pop().ok_or_else(|| unbalanced(')', column))?;
+            }
+            _ => (),
+        }
+    }
+
+    match open.pop() {
+        Some(column) => Err(unbalanced('(', column)),
+        None => Ok(()),
+    }
+}
+
 pub fn lex(input: &str) -> anyhow::Result<Vec<Token>> {
+    check_parens(input)?;
     let lexer: fsm_lexer::Lexer<InputClass, LexerState, Token> =
         fsm_lexer::Lexer::new(LexerState::Initial);
     lexer.lex(input).context("Failed to lex")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn balanced_parens() {
+        assert!(check_parens("(1 + (2 * 3)) - 4\n").is_ok());
+    }
+
+    #[test]
+    fn unbalanced_parens() {
+        let error = check_parens("(1 + (2 * 3)\n").unwrap_err();
+        assert!(error.to_string().starts_with("Unbalanced ( at column 1\n"));
+        let error = check_parens("1 + 2) * 3\n").unwrap_err();
+        assert!(error.to_string().starts_with("Unbalanced ) at column 6\n"));
+    }
+
+    #[test]
+    fn parens_in_strings() {
+        assert!(check_parens("'(' , ')'\n").is_ok());
+        // A doubled quote escapes a quote without closing the literal
+        assert!(check_parens("'it''s ('\n").is_ok());
+        assert!(check_parens("'a' , (\n").is_err());
+    }
+}
@@ -7,6 +7,9 @@ mod arrays;
 mod interpreter;
 mod lexer;
 mod primitives;
+#[cfg(test)]
+mod testing;
+mod verb;
 
 fn main() -> Result<()> {
     interpreter::repl()
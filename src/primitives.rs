@@ -1,29 +1,34 @@
 use crate::arrays::noun::Noun;
+use crate::arrays::IntegerElt;
+use crate::verb::{Operand, INFINITE_RANK};
 use phf::phf_map;
 
 mod monads {
+    use super::{dyads, scalar};
     use crate::arrays::array::Array;
     use crate::arrays::atom::Atom;
     use crate::arrays::generic_array::GenericArray;
-    use crate::arrays::noun::Noun;
-    use crate::arrays::IntegerElt;
+    use crate::arrays::noun::{Noun, Numeric};
+    use crate::arrays::promote::Promote;
+    use crate::arrays::{ComplexElt, DecimalElt, ExtendedElt, IntegerElt, RationalElt};
     use anyhow::{anyhow, Context, Result};
     use itertools::Itertools;
+    use num::{Signed, Zero};
 
     pub fn same(w: Noun) -> Result<Noun> {
         Ok(w)
     }
 
+    pub fn cap(_: Noun) -> Result<Noun> {
+        Err(anyhow!(
+            "Domain error: [: can only be used as the left tine of a fork"
+        ))
+    }
+
     pub fn iota(w: Noun) -> Result<Noun> {
-        let shape = match w {
-            Noun::Atom(Atom::Integer(w)) => vec![w],
-            Noun::Array(Array::Integer(w)) if w.rank() == 1 => w.raw_data().to_vec(),
-            _ => {
-                return Err(anyhow!(
-                    "Incompatible argument: must be an atom or rank-1 integer arrays"
-                ))
-            }
-        };
+        let shape = w
+            .to_integers()
+            .context("Incompatible argument: must be an atom or rank-1 integer array")?;
         Ok(Noun::Array(Array::Integer(GenericArray::iota(&shape))))
     }
 
@@ -37,6 +42,385 @@ mod monads {
             .with_context(|| anyhow!("atoms have no shape"))
     }
 
+    pub fn enclose(w: Noun) -> Result<Noun> {
+        Ok(w.enclose())
+    }
+
+    pub fn open(w: Noun) -> Result<Noun> {
+        let (frame, cells) = w.cells(0);
+        Noun::from_cells(&frame, cells.into_iter().map(Noun::unbox).collect())
+            .context("in monadic > open")
+    }
+
+    /// The elements of `w` as a list.
+    pub fn ravel(w: Noun) -> Result<Noun> {
+        let shape = w.shape().unwrap_or_default();
+        let count = shape.iter().product();
+        w.with_shape(vec![count]).context("in monadic , ravel")
+    }
+
+    /// Each item of `w` as a list, giving a table.
+    pub fn ravel_items(w: Noun) -> Result<Noun> {
+        let shape = match w.shape().unwrap_or_default() {
+            [] => vec![1, 1],
+            [n, rest @ ..] => vec![*n, rest.iter().product()],
+        };
+        w.with_shape(shape).context("in monadic ,. ravel items")
+    }
+
+    /// `w` as the single item of an array.
+    pub fn itemize(w: Noun) -> Result<Noun> {
+        let shape = [&[1], w.shape().unwrap_or_default()].concat();
+        w.with_shape(shape).context("in monadic ,: itemize")
+    }
+
+    /// The first item, or an item of fill if there are none.
+    pub fn head(w: Noun) -> Result<Noun> {
+        let first = dyads::take(Noun::from(1 as IntegerElt), w).context("in monadic {. head")?;
+        Ok(first.items().remove(0))
+    }
+
+    /// The last item, or an item of fill if there are none.
+    pub fn tail(w: Noun) -> Result<Noun> {
+        let last = dyads::take(Noun::from(-1 as IntegerElt), w).context("in monadic {: tail")?;
+        Ok(last.items().remove(0))
+    }
+
+    /// All but the first item.
+    pub fn behead(w: Noun) -> Result<Noun> {
+        dyads::drop(Noun::from(1 as IntegerElt), w).context("in monadic }. behead")
+    }
+
+    /// All but the last item.
+    pub fn curtail(w: Noun) -> Result<Noun> {
+        dyads::drop(Noun::from(-1 as IntegerElt), w).context("in monadic }: curtail")
+    }
+
+    /// Every way of choosing one item from the contents of each box, each boxed.
+    pub fn catalogue(w: Noun) -> Result<Noun> {
+        let choices = w
+            .cells(0)
+            .1
+            .into_iter()
+            .map(|w| w.unbox().items())
+            .collect_vec();
+        let frame = choices.iter().map(Vec::len).collect_vec();
+        let cells = choices
+            .into_iter()
+            .multi_cartesian_product()
+            .map(|items| Ok(Noun::from_items(items)?.enclose()))
+            .collect::<Result<_>>()
+            .context("in monadic { catalogue")?;
+        Noun::from_cells(&frame, cells)
+    }
+
+    pub fn reverse(w: Noun) -> Result<Noun> {
+        Ok(w.reverse())
+    }
+
+    /// Reverse the order of the axes.
+    pub fn transpose(w: Noun) -> Result<Noun> {
+        let axes = (0..w.rank().unwrap_or(0))
+            .rev()
+            .map(|k| vec![k])
+            .collect_vec();
+        Ok(w.transpose(&axes))
+    }
+
+    pub fn grade_up(w: Noun) -> Result<Noun> {
+        let order = grade(w, false).into_iter().map(|i| i as IntegerElt);
+        Ok(Noun::from(GenericArray::new(order.collect_vec())))
+    }
+
+    pub fn grade_down(w: Noun) -> Result<Noun> {
+        let order = grade(w, true).into_iter().map(|i| i as IntegerElt);
+        Ok(Noun::from(GenericArray::new(order.collect_vec())))
+    }
+
+    /// The permutation that sorts the items of `w` by the total array ordering. Equal items keep
+    /// their original order in either direction.
+    pub fn grade(w: Noun, descending: bool) -> Vec<usize> {
+        let items = w.items().into_iter().map(Noun::into_array).collect_vec();
+        let mut order = (0..items.len()).collect_vec();
+        order.sort_by(|&i, &j| {
+            let ordering = items[i].total_cmp(&items[j]);
+            if descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+        order
+    }
+
+    /// Catenate the items of the contents of every box.
+    pub fn raze(w: Noun) -> Result<Noun> {
+        let items = w
+            .cells(0)
+            .1
+            .into_iter()
+            .flat_map(|w| w.unbox().items())
+            .collect();
+        Noun::from_items(items).context("in monadic ; raze")
+    }
+
+    pub fn conjugate(w: Noun) -> Result<Noun> {
+        w.monad(|w| w, |w| w, |w| w, |w| w, |w| w, |w| w.conj())
+            .context("in monadic + conjugate")
+    }
+
+    pub fn negate(w: Noun) -> Result<Noun> {
+        // Subtracting from zero promotes the least integer, whose negation overflows
+        dyads::sub(Noun::from(0 as IntegerElt), w).context("in monadic - negate")
+    }
+
+    pub fn signum(w: Noun) -> Result<Noun> {
+        let sign = |positive: bool, negative: bool| match (positive, negative) {
+            (true, _) => 1 as IntegerElt,
+            (_, true) => -1,
+            _ => 0,
+        };
+        w.monad(
+            |w| w,
+            IntegerElt::signum,
+            |w| sign(w.is_positive(), w.is_negative()),
+            |w| sign(w.is_positive(), w.is_negative()),
+            |w| sign(w > 0.0, w < 0.0),
+            |w| if w.is_zero() { w } else { w / w.norm() },
+        )
+        .context("in monadic * signum")
+    }
+
+    pub fn reciprocal(w: Noun) -> Result<Noun> {
+        dyads::div(Noun::from(1 as IntegerElt), w).context("in monadic % reciprocal")
+    }
+
+    pub fn magnitude(w: Noun) -> Result<Noun> {
+        // The least integer has no positive counterpart, so its magnitude is taken in decimals
+        let overflows = match &w {
+            Noun::Atom(Atom::Integer(w)) => *w == IntegerElt::MIN,
+            Noun::Array(Array::Integer(w)) => w.raw_data().contains(&IntegerElt::MIN),
+            _ => false,
+        };
+        let w = if overflows {
+            w.promote_to(Numeric::Decimal)
+        } else {
+            w
+        };
+        w.monad(
+            |w| w,
+            |w| w.abs(),
+            |w| w.abs(),
+            |w| w.abs(),
+            |w| w.abs(),
+            |w| w.norm(),
+        )
+        .context("in monadic | magnitude")
+    }
+
+    /// The length and angle of each atom, as a list along a new final axis.
+    pub fn polar(w: Noun) -> Result<Noun> {
+        let (frame, cells) = w.promote_to(Numeric::Complex).cells(0);
+        let cells = cells
+            .into_iter()
+            .map(|w| match w {
+                Noun::Atom(Atom::Complex(w)) => {
+                    let (r, theta) = w.to_polar();
+                    Ok(Noun::from(GenericArray::new(vec![r, theta])))
+                }
+                w => Err(anyhow!("Domain error: expected a number, got {:?}", w)),
+            })
+            .collect::<Result<_>>()
+            .context("in monadic *. polar")?;
+        Noun::from_cells(&frame, cells)
+    }
+
+    /// The real and imaginary parts of each atom, as a list along a new final axis.
+    pub fn real_imaginary(w: Noun) -> Result<Noun> {
+        let (frame, cells) = w.promote_to(Numeric::Complex).cells(0);
+        let cells = cells
+            .into_iter()
+            .map(|w| match w {
+                Noun::Atom(Atom::Complex(w)) => Ok(Noun::from(GenericArray::new(vec![w.re, w.im]))),
+                w => Err(anyhow!("Domain error: expected a number, got {:?}", w)),
+            })
+            .collect::<Result<_>>()
+            .context("in monadic +. real/imaginary")?;
+        Noun::from_cells(&frame, cells)
+    }
+
+    /// Square roots of negative numbers are complex.
+    pub fn sqrt(w: Noun) -> Result<Noun> {
+        let w = if any_negative(&w).context("in monadic %: square root")? {
+            w.promote_to(Numeric::Complex)
+        } else {
+            w
+        };
+        transcendental(w, DecimalElt::sqrt, ComplexElt::sqrt)
+    }
+
+    pub fn exp(w: Noun) -> Result<Noun> {
+        transcendental(w, DecimalElt::exp, ComplexElt::exp).context("in monadic ^ exponential")
+    }
+
+    /// Logarithms of negative numbers are complex.
+    pub fn log(w: Noun) -> Result<Noun> {
+        let w = if any_negative(&w).context("in monadic ^. logarithm")? {
+            w.promote_to(Numeric::Complex)
+        } else {
+            w
+        };
+        transcendental(w, DecimalElt::ln, ComplexElt::ln)
+    }
+
+    fn any_negative(w: &Noun) -> Result<bool> {
+        Ok(w.clone()
+            .map(
+                |_| false,
+                |w| w < 0,
+                |w| w.is_negative(),
+                |w| w.is_negative(),
+                |w| w < 0.0,
+                |_| false,
+            )?
+            .elements()
+            .contains(&true))
+    }
+
+    /// Apply a function of decimals, or its complex counterpart, computing exact numbers in
+    /// decimals.
+    fn transcendental<D, J>(w: Noun, d: D, j: J) -> Result<Noun>
+    where
+        D: Fn(DecimalElt) -> DecimalElt,
+        J: Fn(ComplexElt) -> ComplexElt,
+    {
+        w.monad(
+            |w| d(<bool as Promote<DecimalElt>>::promote(w)),
+            |w| d(<IntegerElt as Promote<DecimalElt>>::promote(w)),
+            |w| d(<ExtendedElt as Promote<DecimalElt>>::promote(w)),
+            |w| d(<RationalElt as Promote<DecimalElt>>::promote(w)),
+            &d,
+            j,
+        )
+    }
+
+    pub fn floor(w: Noun) -> Result<Noun> {
+        let ct = scalar::tolerance();
+        w.monad(
+            |w| w,
+            |w| w,
+            |w| w,
+            |w| w.floor().to_integer(),
+            |w| scalar::floor(w, ct),
+            |w| ComplexElt::new(scalar::floor(w.re, ct), scalar::floor(w.im, ct)),
+        )
+        .map(Noun::demote)
+        .context("in monadic <. floor")
+    }
+
+    pub fn ceiling(w: Noun) -> Result<Noun> {
+        let ct = scalar::tolerance();
+        w.monad(
+            |w| w,
+            |w| w,
+            |w| w,
+            |w| w.ceil().to_integer(),
+            |w| -scalar::floor(-w, ct),
+            |w| ComplexElt::new(-scalar::floor(-w.re, ct), -scalar::floor(-w.im, ct)),
+        )
+        .map(Noun::demote)
+        .context("in monadic >. ceiling")
+    }
+
+    /// Factorials of whole numbers are exact where possible, and extend to other numbers through
+    /// the gamma function.
+    pub fn factorial(w: Noun) -> Result<Noun> {
+        let integral = matches!(w.numeric(), Some(Numeric::Boolean | Numeric::Integer));
+        let inexact = w
+            .clone()
+            .map(
+                |_| false,
+                |_| false,
+                |w| w.is_negative(),
+                |w| w.is_negative() || !w.is_integer(),
+                |_| false,
+                |_| false,
+            )
+            .context("in monadic ! factorial")?
+            .elements()
+            .contains(&true);
+        let w = if inexact {
+            w.promote_to(Numeric::Decimal)
+        } else {
+            w
+        };
+        let result = w
+            .real_monad(
+                |_| 1 as IntegerElt,
+                |w| scalar::factorial(w as DecimalElt),
+                scalar::exact_factorial,
+                scalar::exact_factorial,
+                scalar::factorial,
+            )
+            .context("in monadic ! factorial")?;
+        Ok(if integral { result.demote() } else { result })
+    }
+
+    pub fn not(w: Noun) -> Result<Noun> {
+        match w {
+            w @ Noun::Atom(Atom::Boolean(_)) | w @ Noun::Array(Array::Boolean(_)) => {
+                w.monad(|w| !w, |w| w, |w| w, |w| w, |w| w, |w| w)
+            }
+            w => dyads::sub(Noun::from(1 as IntegerElt), w),
+        }
+        .context("in monadic -. not")
+    }
+
+    pub fn decrement(w: Noun) -> Result<Noun> {
+        dyads::sub(w, Noun::from(1 as IntegerElt)).context("in monadic <: decrement")
+    }
+
+    pub fn increment(w: Noun) -> Result<Noun> {
+        dyads::add(w, Noun::from(1 as IntegerElt)).context("in monadic >: increment")
+    }
+
+    pub fn double(w: Noun) -> Result<Noun> {
+        dyads::add(w.clone(), w).context("in monadic +: double")
+    }
+
+    pub fn halve(w: Noun) -> Result<Noun> {
+        dyads::div(w, Noun::from(2 as IntegerElt)).context("in monadic -: halve")
+    }
+
+    pub fn square(w: Noun) -> Result<Noun> {
+        dyads::mul(w.clone(), w).context("in monadic *: square")
+    }
+
+    /// Mark the first occurrence of each distinct item.
+    pub fn nub_sieve(w: Noun) -> Result<Noun> {
+        let items = w.items();
+        let sieve = (0..items.len())
+            .map(|i| !items[..i].iter().any(|u| dyads::matches(u, &items[i])))
+            .collect_vec();
+        Ok(Noun::from(GenericArray::new(sieve)))
+    }
+
+    /// The distinct items, in the order they first occur. An atom gives a list of one.
+    pub fn nub(w: Noun) -> Result<Noun> {
+        let items = w.clone().items();
+        let mut nub: Vec<Noun> = vec![];
+        for item in &items {
+            if !nub.iter().any(|u| dyads::matches(u, item)) {
+                nub.push(item.clone());
+            }
+        }
+        match w {
+            Noun::Atom(_) => Noun::from_items(vec![w]),
+            _ if nub.len() == items.len() => Ok(w),
+            _ => Noun::from_items(nub).context("in monadic ~. nub"),
+        }
+    }
+
     pub fn count(w: Noun) -> Result<Noun> {
         w.shape()
             .and_then(|s| s.first())
@@ -47,22 +431,295 @@ mod monads {
 
 type MonadFn = fn(Noun) -> anyhow::Result<Noun>;
 
-pub static MONADS: phf::Map<&'static str, MonadFn> = phf_map! {
-    "]" => monads::same,
-    "[" => monads::same,
-    "i." => monads::iota,
-    "$" => monads::shape_of,
-    "#" => monads::count,
+#[derive(Debug, Copy, Clone)]
+pub struct Monad {
+    pub f: MonadFn,
+    pub rank: IntegerElt,
+}
+
+pub static MONADS: phf::Map<&'static str, Monad> = phf_map! {
+    "]" => Monad { f: monads::same, rank: INFINITE_RANK },
+    "[" => Monad { f: monads::same, rank: INFINITE_RANK },
+    "[:" => Monad { f: monads::cap, rank: INFINITE_RANK },
+    "i." => Monad { f: monads::iota, rank: 1 },
+    "$" => Monad { f: monads::shape_of, rank: INFINITE_RANK },
+    "#" => Monad { f: monads::count, rank: INFINITE_RANK },
+    "<" => Monad { f: monads::enclose, rank: INFINITE_RANK },
+    ">" => Monad { f: monads::open, rank: 0 },
+    ";" => Monad { f: monads::raze, rank: INFINITE_RANK },
+    "," => Monad { f: monads::ravel, rank: INFINITE_RANK },
+    ",." => Monad { f: monads::ravel_items, rank: INFINITE_RANK },
+    ",:" => Monad { f: monads::itemize, rank: INFINITE_RANK },
+    "{" => Monad { f: monads::catalogue, rank: 1 },
+    "|." => Monad { f: monads::reverse, rank: INFINITE_RANK },
+    "/:" => Monad { f: monads::grade_up, rank: INFINITE_RANK },
+    "\\:" => Monad { f: monads::grade_down, rank: INFINITE_RANK },
+    "|:" => Monad { f: monads::transpose, rank: INFINITE_RANK },
+    "{." => Monad { f: monads::head, rank: INFINITE_RANK },
+    "{:" => Monad { f: monads::tail, rank: INFINITE_RANK },
+    "}." => Monad { f: monads::behead, rank: INFINITE_RANK },
+    "}:" => Monad { f: monads::curtail, rank: INFINITE_RANK },
+    "+" => Monad { f: monads::conjugate, rank: 0 },
+    "-" => Monad { f: monads::negate, rank: 0 },
+    "*" => Monad { f: monads::signum, rank: 0 },
+    "%" => Monad { f: monads::reciprocal, rank: 0 },
+    "|" => Monad { f: monads::magnitude, rank: 0 },
+    "*." => Monad { f: monads::polar, rank: 0 },
+    "%:" => Monad { f: monads::sqrt, rank: 0 },
+    "~." => Monad { f: monads::nub, rank: INFINITE_RANK },
+    "+." => Monad { f: monads::real_imaginary, rank: 0 },
+    "^" => Monad { f: monads::exp, rank: 0 },
+    "^." => Monad { f: monads::log, rank: 0 },
+    "<." => Monad { f: monads::floor, rank: 0 },
+    ">." => Monad { f: monads::ceiling, rank: 0 },
+    "!" => Monad { f: monads::factorial, rank: 0 },
+    "-." => Monad { f: monads::not, rank: 0 },
+    "<:" => Monad { f: monads::decrement, rank: 0 },
+    ">:" => Monad { f: monads::increment, rank: 0 },
+    "+:" => Monad { f: monads::double, rank: 0 },
+    "-:" => Monad { f: monads::halve, rank: 0 },
+    "*:" => Monad { f: monads::square, rank: 0 },
+    "~:" => Monad { f: monads::nub_sieve, rank: INFINITE_RANK },
 };
 
+/// Scalar functions shared between primitives and the element types they work on.
+mod scalar {
+    use crate::arrays::{ComplexElt, DecimalElt};
+    use num::{Num, Signed};
+    use std::cell::Cell;
+    use std::cmp::Ordering;
+
+    /// The default relative tolerance for comparing decimals, 2^-44.
+    pub const DEFAULT_TOLERANCE: DecimalElt = 5.684_341_886_080_802e-14;
+
+    thread_local! {
+        static TOLERANCE: Cell<DecimalElt> = const { Cell::new(DEFAULT_TOLERANCE) };
+    }
+
+    /// The comparison tolerance currently in effect.
+    pub fn tolerance() -> DecimalElt {
+        TOLERANCE.with(Cell::get)
+    }
+
+    /// Run `f` with a different comparison tolerance, as set by `!.`.
+    pub fn with_tolerance<T>(ct: DecimalElt, f: impl FnOnce() -> T) -> T {
+        let previous = TOLERANCE.with(|t| t.replace(ct));
+        let result = f();
+        TOLERANCE.with(|t| t.set(previous));
+        result
+    }
+
+    /// Whether two decimals are equal within the relative tolerance `ct`.
+    pub fn tolerant_eq(a: DecimalElt, w: DecimalElt, ct: DecimalElt) -> bool {
+        a == w || (a - w).abs() <= ct * a.abs().max(w.abs())
+    }
+
+    /// Whether two complex numbers are equal within the relative tolerance `ct`.
+    pub fn tolerant_eq_complex(a: ComplexElt, w: ComplexElt, ct: DecimalElt) -> bool {
+        a == w || (a - w).norm() <= ct * a.norm().max(w.norm())
+    }
+
+    /// Order two decimals, treating those within the relative tolerance `ct` as equal.
+    pub fn tolerant_cmp(a: DecimalElt, w: DecimalElt, ct: DecimalElt) -> Ordering {
+        if tolerant_eq(a, w, ct) {
+            Ordering::Equal
+        } else {
+            a.total_cmp(&w)
+        }
+    }
+
+    /// The floor of a decimal, rounding instead when within the tolerance of a whole number.
+    pub fn floor(w: DecimalElt, ct: DecimalElt) -> DecimalElt {
+        let r = w.round();
+        if tolerant_eq(w, r, ct) {
+            r
+        } else {
+            w.floor()
+        }
+    }
+
+    /// Decimal factorials are products where whole, and otherwise use the gamma function.
+    pub fn factorial(w: DecimalElt) -> DecimalElt {
+        if w.fract() != 0.0 {
+            gamma(w + 1.0)
+        } else if w < 0.0 {
+            DecimalElt::INFINITY
+        } else if w > 170.0 {
+            // Larger factorials overflow
+            DecimalElt::INFINITY
+        } else {
+            (1..=w as u32).map(DecimalElt::from).product()
+        }
+    }
+
+    /// The factorial of a whole, non-negative number.
+    pub fn exact_factorial<T>(w: T) -> T
+    where
+        T: Num + PartialOrd + Clone,
+    {
+        let (mut result, mut i) = (T::one(), T::one());
+        while i <= w {
+            result = result * i.clone();
+            i = i + T::one();
+        }
+        result
+    }
+
+    /// The greatest common divisor of two decimals, by Euclid's algorithm, stopping once a
+    /// divisor leaves a remainder within the tolerance of zero.
+    pub fn gcd(a: DecimalElt, w: DecimalElt, ct: DecimalElt) -> DecimalElt {
+        let (mut a, mut w) = (a.abs(), w.abs());
+        while w > 0.0 {
+            let r = a % w;
+            if r <= ct * w || w - r <= ct * w {
+                return w;
+            }
+            (a, w) = (w, r);
+        }
+        a
+    }
+
+    /// The least common multiple, with the sign of the product.
+    pub fn lcm<T>(a: T, w: T, gcd: impl Fn(T, T) -> T) -> T
+    where
+        T: Num + Clone,
+    {
+        let g = gcd(a.clone(), w.clone());
+        if g.is_zero() {
+            g
+        } else {
+            a / g * w
+        }
+    }
+
+    /// The number of ways to choose `k` things from `n`, extended to negative `n` by
+    /// `k ! n = (-1^k) * k ! k-n+1`. Both arguments must be whole.
+    pub fn out_of<T>(k: T, n: T) -> T
+    where
+        T: Num + Signed + PartialOrd + Clone,
+    {
+        binomial(k, n, choose)
+    }
+
+    /// The number of ways to choose `k` things from `n` for any real arguments, by the gamma
+    /// function where they are not whole.
+    pub fn out_of_decimal(k: DecimalElt, n: DecimalElt) -> DecimalElt {
+        if k.fract() == 0.0 && n.fract() == 0.0 {
+            binomial(k, n, choose_decimal)
+        } else if k >= 0.0 && n - k >= 0.0 {
+            (ln_gamma(n + 1.0) - ln_gamma(k + 1.0) - ln_gamma(n - k + 1.0)).exp()
+        } else {
+            gamma(n + 1.0) / (gamma(k + 1.0) * gamma(n - k + 1.0))
+        }
+    }
+
+    /// Extend `choose`, which needs `0 <= k <= n`, to all whole arguments.
+    fn binomial<T>(k: T, n: T, choose: fn(T, T) -> T) -> T
+    where
+        T: Num + Signed + PartialOrd + Clone,
+    {
+        let odd = |w: &T| !(w.clone() % (T::one() + T::one())).is_zero();
+        let signed = |negative: bool, w: T| if negative { -w } else { w };
+        if !n.is_negative() {
+            if k.is_negative() || k > n {
+                T::zero()
+            } else {
+                choose(k, n)
+            }
+        } else if !k.is_negative() {
+            signed(odd(&k), choose(k.clone(), k - n - T::one()))
+        } else if k <= n {
+            let d = n.clone() - k.clone();
+            signed(odd(&d), choose(d, -k - T::one()))
+        } else {
+            T::zero()
+        }
+    }
+
+    /// The binomial coefficient for `0 <= k <= n`.
+    fn choose<T>(k: T, n: T) -> T
+    where
+        T: Num + PartialOrd + Clone,
+    {
+        let k = if n.clone() - k.clone() < k {
+            n.clone() - k
+        } else {
+            k
+        };
+        let (mut result, mut i) = (T::one(), T::one());
+        // Each partial product is itself a binomial coefficient, so the division is exact
+        while i <= k {
+            result = result * (n.clone() - k.clone() + i.clone()) / i.clone();
+            i = i + T::one();
+        }
+        result
+    }
+
+    /// The binomial coefficient of whole decimals for `0 <= k <= n`. Multiplying out a great many
+    /// terms is slow, so larger coefficients use the gamma function instead.
+    fn choose_decimal(k: DecimalElt, n: DecimalElt) -> DecimalElt {
+        if k.min(n - k) <= 1000.0 {
+            choose(k, n)
+        } else {
+            (ln_gamma(n + 1.0) - ln_gamma(k + 1.0) - ln_gamma(n - k + 1.0)).exp()
+        }
+    }
+
+    /// The gamma function, by the Lanczos approximation.
+    pub fn gamma(x: f64) -> f64 {
+        if x < 0.5 {
+            // Reflection formula
+            std::f64::consts::PI / ((std::f64::consts::PI * x).sin() * gamma(1.0 - x))
+        } else {
+            let (x, t, sum) = lanczos(x);
+            (2.0 * std::f64::consts::PI).sqrt() * t.powf(x + 0.5) * (-t).exp() * sum
+        }
+    }
+
+    /// The logarithm of the gamma function for `x >= 0.5`, which stays finite long after the
+    /// gamma function overflows.
+    fn ln_gamma(x: f64) -> f64 {
+        let (x, t, sum) = lanczos(x);
+        0.5 * (2.0 * std::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + sum.ln()
+    }
+
+    /// The terms of the Lanczos approximation shared by `gamma` and `ln_gamma`.
+    fn lanczos(x: f64) -> (f64, f64, f64) {
+        const G: f64 = 7.0;
+        const C: [f64; 9] = [
+            0.999_999_999_999_809_9,
+            676.520_368_121_885_1,
+            -1_259.139_216_722_402_8,
+            771.323_428_777_653_1,
+            -176.615_029_162_140_6,
+            12.507_343_278_686_905,
+            -0.138_571_095_265_720_12,
+            9.984_369_578_019_572e-6,
+            1.505_632_735_149_311_6e-7,
+        ];
+        let x = x - 1.0;
+        let t = x + G + 0.5;
+        let sum = C[1..]
+            .iter()
+            .enumerate()
+            .fold(C[0], |acc, (i, c)| acc + c / (x + i as f64 + 1.0));
+        (x, t, sum)
+    }
+}
+
 mod dyads {
-    use crate::arrays::generic_array::GenericArray;
+    use super::scalar;
     use crate::arrays::generic_matching_nouns::GenericMatchingNouns;
+    use crate::arrays::matching_nouns::MatchingNouns;
     use crate::arrays::noun::Noun;
     use crate::arrays::promote::Promote;
-    use crate::arrays::{DecimalElt, IntegerElt};
+    use crate::arrays::{DecimalElt, IntegerElt, RationalElt};
+    use crate::verb::dyad_rank;
     use anyhow::{anyhow, Context, Result};
-    use std::path::Display;
+    use itertools::Itertools;
+    use num::{Integer, Signed, ToPrimitive, Zero};
+    use std::cmp::Ordering;
+    use std::iter::zip;
 
     pub fn same_w(_: Noun, w: Noun) -> Result<Noun> {
         Ok(w)
@@ -72,71 +729,1621 @@ mod dyads {
         Ok(a)
     }
 
+    pub fn cap(_: Noun, _: Noun) -> Result<Noun> {
+        Err(anyhow!(
+            "Domain error: [: can only be used as the left tine of a fork"
+        ))
+    }
+
     pub fn add(a: Noun, w: Noun) -> Result<Noun> {
         Noun::try_promote_pair(a, w)
+            .and_then(|n| n.promote_on_overflow(IntegerElt::checked_add))
             .context("in dyadic + add")?
             .dyad(
                 |a, w| a as IntegerElt + w as IntegerElt,
                 |a, w| a + w,
                 |a, w| a + w,
+                |a, w| a + w,
+                |a, w| a + w,
+                |a, w| a + w,
             )
     }
 
     pub fn sub(a: Noun, w: Noun) -> Result<Noun> {
         Noun::try_promote_pair(a, w)
+            .and_then(|n| n.promote_on_overflow(IntegerElt::checked_sub))
             .context("in dyadic - sub")?
             .dyad(
                 |a, w| a as IntegerElt - w as IntegerElt,
                 |a, w| a - w,
                 |a, w| a - w,
+                |a, w| a - w,
+                |a, w| a - w,
+                |a, w| a - w,
             )
     }
 
     pub fn mul(a: Noun, w: Noun) -> Result<Noun> {
         Noun::try_promote_pair(a, w)
+            .and_then(|n| n.promote_on_overflow(IntegerElt::checked_mul))
             .context("in dyadic * mul")?
-            .dyad(|a, w| a && w, |a, w| a * w, |a, w| a * w)
+            .dyad(
+                |a, w| a && w,
+                |a, w| a * w,
+                |a, w| a * w,
+                |a, w| a * w,
+                |a, w| a * w,
+                |a, w| a * w,
+            )
     }
 
     pub fn div(a: Noun, w: Noun) -> Result<Noun> {
-        Noun::try_promote_pair(a, w)
-            .context("in dyadic % div")?
-            .dyad(
-                |a, w| {
-                    <bool as Promote<DecimalElt>>::promote(a)
-                        / <bool as Promote<DecimalElt>>::promote(w)
-                },
+        // Exact division by zero is impossible, so it gives a decimal infinity instead
+        let nouns = match Noun::try_promote_pair(a, w).context("in dyadic % div")? {
+            MatchingNouns::Extended(n) if n.elements().1.iter().any(Zero::is_zero) => {
+                MatchingNouns::Decimal(n.promote())
+            }
+            MatchingNouns::Rational(n) if n.elements().1.iter().any(Zero::is_zero) => {
+                MatchingNouns::Decimal(n.promote())
+            }
+            nouns => nouns,
+        };
+        // Whole quotients of exact arguments are integers, extended for extended or rational ones
+        let integral = matches!(
+            nouns,
+            MatchingNouns::Boolean(_)
+                | MatchingNouns::Integer(_)
+                | MatchingNouns::Extended(_)
+                | MatchingNouns::Rational(_)
+        );
+        let result = nouns.dyad(
+            |a, w| {
+                <bool as Promote<DecimalElt>>::promote(a)
+                    / <bool as Promote<DecimalElt>>::promote(w)
+            },
+            |a, w| {
+                <IntegerElt as Promote<DecimalElt>>::promote(a)
+                    / <IntegerElt as Promote<DecimalElt>>::promote(w)
+            },
+            RationalElt::new,
+            |a, w| a / w,
+            |a, w| a / w,
+            |a, w| a / w,
+        )?;
+        Ok(if integral { result.demote() } else { result })
+    }
+
+    pub fn power(a: Noun, w: Noun) -> Result<Noun> {
+        let nouns = match Noun::try_promote_pair(a, w).context("in dyadic ^ power")? {
+            MatchingNouns::Extended(n) if n.elements().1.iter().all(|w| w.to_u32().is_some()) => {
+                MatchingNouns::Extended(n)
+            }
+            // Negative powers of extended integers are rational
+            MatchingNouns::Extended(n) => rational_powers(n.promote()),
+            MatchingNouns::Rational(n) => rational_powers(n),
+            nouns => nouns,
+        };
+        let nouns = match nouns {
+            // Fractional powers of negative numbers are complex
+            MatchingNouns::Decimal(n)
+                if n.elements().0.iter().any(|&a| a < 0.0)
+                    && n.elements().1.iter().any(|w| w.fract() != 0.0) =>
+            {
+                MatchingNouns::Complex(n.promote())
+            }
+            nouns => nouns,
+        };
+        let integral = matches!(nouns, MatchingNouns::Boolean(_) | MatchingNouns::Integer(_));
+        let result = nouns.dyad(
+            |a, w| a || !w,
+            |a, w| {
+                <IntegerElt as Promote<DecimalElt>>::promote(a)
+                    .powf(<IntegerElt as Promote<DecimalElt>>::promote(w))
+            },
+            |a, w| num::pow(a, w.to_usize().unwrap_or_default()),
+            |a, w| a.pow(w.to_i32().unwrap_or_default()),
+            |a, w| a.powf(w),
+            |a, w| a.powc(w),
+        )?;
+        Ok(if integral { result.demote() } else { result })
+    }
+
+    /// Rational powers are exact for whole exponents, unless zero is raised to a negative power.
+    /// Anything else is computed in decimals.
+    fn rational_powers(nouns: GenericMatchingNouns<RationalElt>) -> MatchingNouns {
+        let (a, w) = nouns.elements();
+        let whole = w.iter().all(|w| w.is_integer() && w.to_i32().is_some());
+        let infinite = a.iter().any(Zero::is_zero) && w.iter().any(Signed::is_negative);
+        if whole && !infinite {
+            MatchingNouns::Rational(nouns)
+        } else {
+            MatchingNouns::Decimal(nouns.promote())
+        }
+    }
+
+    /// `a ! w` is the number of ways to choose `a` things from `w`.
+    pub fn out_of(a: Noun, w: Noun) -> Result<Noun> {
+        let nouns = match Noun::try_promote_pair(a, w).context("in dyadic ! out of")? {
+            MatchingNouns::Rational(n) => {
+                let (a, w) = n.elements();
+                if a.iter().chain(w).all(RationalElt::is_integer) {
+                    MatchingNouns::Rational(n)
+                } else {
+                    MatchingNouns::Decimal(n.promote())
+                }
+            }
+            nouns => nouns,
+        };
+        let integral = matches!(nouns, MatchingNouns::Boolean(_) | MatchingNouns::Integer(_));
+        let result = nouns
+            .real_dyad(
+                |a, w| !a || w,
                 |a, w| {
-                    <IntegerElt as Promote<DecimalElt>>::promote(a)
-                        / <IntegerElt as Promote<DecimalElt>>::promote(w)
+                    scalar::out_of_decimal(
+                        <IntegerElt as Promote<DecimalElt>>::promote(a),
+                        <IntegerElt as Promote<DecimalElt>>::promote(w),
+                    )
                 },
-                |a, w| a / w,
+                scalar::out_of,
+                scalar::out_of,
+                scalar::out_of_decimal,
             )
+            .context("in dyadic ! out of")?;
+        Ok(if integral { result.demote() } else { result })
+    }
+
+    /// `a ^. w` is the base `a` logarithm of `w`.
+    pub fn log(a: Noun, w: Noun) -> Result<Noun> {
+        div(super::monads::log(w)?, super::monads::log(a)?).context("in dyadic ^. logarithm")
+    }
+
+    /// `a %: w` is the `a`th root of `w`.
+    pub fn root(a: Noun, w: Noun) -> Result<Noun> {
+        let a = div(Noun::from(1 as IntegerElt), a).context("in dyadic %: root")?;
+        power(w, a).context("in dyadic %: root")
+    }
+
+    /// `a | w` is the remainder of dividing `w` by `a`, with the sign of `a`. `0 | w` is `w`.
+    pub fn residue(a: Noun, w: Noun) -> Result<Noun> {
+        let ct = scalar::tolerance();
+        Noun::try_promote_pair(a, w)
+            .and_then(|n| {
+                n.real_dyad(
+                    |a, w| !a && w,
+                    |a, w| match a {
+                        0 => w,
+                        -1 => 0,
+                        a => w.mod_floor(&a),
+                    },
+                    |a, w| if a.is_zero() { w } else { w.mod_floor(&a) },
+                    |a, w| {
+                        if a.is_zero() {
+                            w
+                        } else {
+                            w.clone() - a.clone() * (w / a).floor()
+                        }
+                    },
+                    |a, w| {
+                        if a == 0.0 {
+                            w
+                        } else {
+                            w - a * scalar::floor(w / a, ct)
+                        }
+                    },
+                )
+            })
+            .context("in dyadic | residue")
+    }
+
+    pub fn min(a: Noun, w: Noun) -> Result<Noun> {
+        Noun::try_promote_pair(a, w)
+            .and_then(|n| {
+                n.real_dyad(
+                    |a, w| a && w,
+                    |a, w| a.min(w),
+                    |a, w| a.min(w),
+                    |a, w| a.min(w),
+                    |a, w| a.min(w),
+                )
+            })
+            .context("in dyadic <. lesser of")
+    }
+
+    pub fn max(a: Noun, w: Noun) -> Result<Noun> {
+        Noun::try_promote_pair(a, w)
+            .and_then(|n| {
+                n.real_dyad(
+                    |a, w| a || w,
+                    |a, w| a.max(w),
+                    |a, w| a.max(w),
+                    |a, w| a.max(w),
+                    |a, w| a.max(w),
+                )
+            })
+            .context("in dyadic >. greater of")
+    }
+
+    /// Greatest common divisor, which is or on booleans.
+    pub fn gcd(a: Noun, w: Noun) -> Result<Noun> {
+        let ct = scalar::tolerance();
+        Noun::try_promote_pair(a, w)
+            .and_then(|n| n.promote_on_overflow(checked_gcd))
+            .and_then(|n| {
+                n.real_dyad(
+                    |a, w| a || w,
+                    |a, w| a.gcd(&w),
+                    |a, w| a.gcd(&w),
+                    rational_gcd,
+                    |a, w| scalar::gcd(a, w, ct),
+                )
+            })
+            .context("in dyadic +. gcd")
+    }
+
+    /// Least common multiple, which is and on booleans.
+    pub fn lcm(a: Noun, w: Noun) -> Result<Noun> {
+        let ct = scalar::tolerance();
+        Noun::try_promote_pair(a, w)
+            .and_then(|n| {
+                n.promote_on_overflow(|a, w| {
+                    let g = checked_gcd(a, w)?;
+                    if g == 0 {
+                        Some(0)
+                    } else {
+                        (a / g).checked_mul(w)
+                    }
+                })
+            })
+            .and_then(|n| {
+                n.real_dyad(
+                    |a, w| a && w,
+                    |a, w| scalar::lcm(a, w, |a, w| a.gcd(&w)),
+                    |a, w| scalar::lcm(a, w, |a, w| a.gcd(&w)),
+                    |a, w| scalar::lcm(a, w, rational_gcd),
+                    |a, w| scalar::lcm(a, w, |a, w| scalar::gcd(a, w, ct)),
+                )
+            })
+            .context("in dyadic *. lcm")
+    }
+
+    /// The gcd of two integers, unless it is 2^63, as for the least integer and zero.
+    fn checked_gcd(a: IntegerElt, w: IntegerElt) -> Option<IntegerElt> {
+        IntegerElt::try_from(a.unsigned_abs().gcd(&w.unsigned_abs())).ok()
+    }
+
+    fn rational_gcd(a: RationalElt, w: RationalElt) -> RationalElt {
+        let n = (a.numer() * w.denom()).gcd(&(w.numer() * a.denom()));
+        RationalElt::new(n, a.denom() * w.denom())
     }
 
-    pub fn and(a: Noun, w: Noun) -> Result<Noun> {
-        GenericMatchingNouns::from((a.into_boolean(), w.into_boolean()))
-            .dyad(|a, w| a && w)
-            .context("in dyadic *. and")
+    pub fn nor(a: Noun, w: Noun) -> Result<Noun> {
+        booleans(a, w)
+            .and_then(|n| n.dyad(|a, w| !(a || w)))
+            .context("in dyadic +: not-or")
             .map(Noun::from)
     }
 
+    pub fn nand(a: Noun, w: Noun) -> Result<Noun> {
+        booleans(a, w)
+            .and_then(|n| n.dyad(|a, w| !(a && w)))
+            .context("in dyadic *: not-and")
+            .map(Noun::from)
+    }
+
+    /// Both arguments as booleans, for the verbs defined only on 0 and 1.
+    fn booleans(a: Noun, w: Noun) -> Result<GenericMatchingNouns<bool>> {
+        Ok(GenericMatchingNouns::from((
+            a.into_boolean()?,
+            w.into_boolean()?,
+        )))
+    }
+
+    /// `a $ w` arranges the items of `w` into the frame `a`.
+    pub fn reshape(a: Noun, w: Noun) -> Result<Noun> {
+        let frame = match a.shape() {
+            // An empty shape may be of any type, as with ''
+            Some([0]) => vec![],
+            _ => a
+                .to_integers()
+                .and_then(|a| {
+                    a.into_iter()
+                        .map(|a| {
+                            usize::try_from(a)
+                                .map_err(|_| anyhow!("Domain error: {} is not a valid length", a))
+                        })
+                        .collect::<Result<Vec<_>>>()
+                })
+                .context("in dyadic $ reshape")?,
+        };
+        w.reshape(&frame).context("in dyadic $ reshape")
+    }
+
+    /// `a , w` joins the items of `a` and `w`. An atom is repeated to the shape of an item of the
+    /// other argument, an argument of lower rank becomes a single item, and items are padded to a
+    /// common shape.
+    pub fn append(a: Noun, w: Noun) -> Result<Noun> {
+        let (a, w) = untyped_empties(a, w)?;
+        let rank = a.rank().unwrap_or(0).max(w.rank().unwrap_or(0)).max(1);
+        let items = |u: Noun, other: &Noun| -> Result<Vec<Noun>> {
+            Ok(match u.rank() {
+                Some(r) if r == rank => u.items(),
+                Some(_) => vec![u],
+                None => {
+                    let shape = other.shape().unwrap_or_default();
+                    vec![u.reshape(shape.get(1..).unwrap_or_default())?]
+                }
+            })
+        };
+        let items = [items(a.clone(), &w)?, items(w, &a)?].concat();
+        if items.is_empty() {
+            return Ok(a);
+        }
+        Noun::from_items(items).context("in dyadic , append")
+    }
+
+    /// `a ,: w` makes `a` and `w` the two items of a new array. An atom is repeated to the shape
+    /// of the other argument.
+    pub fn laminate(a: Noun, w: Noun) -> Result<Noun> {
+        let (a, w) = untyped_empties(a, w)?;
+        let (a, w) = match (a.shape(), w.shape()) {
+            (None, Some(shape)) => (a.reshape(shape)?, w),
+            (Some(shape), None) => {
+                let w = w.reshape(shape)?;
+                (a, w)
+            }
+            _ => (a, w),
+        };
+        Noun::from_items(vec![a, w]).context("in dyadic ,: laminate")
+    }
+
+    /// An empty argument has no elements to give it a type, so takes the type of the other, as
+    /// when joining `''` to numbers.
+    fn untyped_empties(a: Noun, w: Noun) -> Result<(Noun, Noun)> {
+        let empty = |u: &Noun| u.shape().is_some_and(|shape| shape.contains(&0));
+        let retype = |u: Noun, other: &Noun| -> Result<Noun> {
+            let shape = u.shape().unwrap_or_default().to_vec();
+            other.clone().reshape(&[0])?.with_shape(shape)
+        };
+        Ok(match (empty(&a), empty(&w)) {
+            (true, false) => (retype(a, &w)?, w),
+            (false, true) => {
+                let w = retype(w, &a)?;
+                (a, w)
+            }
+            _ => (a, w),
+        })
+    }
+
+    /// `a {. w` takes `a` items from the start of `w`, or from the end if `a` is negative, padding
+    /// with fill if there are too few. Each element of a list `a` takes along successive axes.
+    pub fn take(a: Noun, w: Noun) -> Result<Noun> {
+        section(a, w, |count, length| {
+            let n = count.unsigned_abs() as usize;
+            let offset = if count < 0 {
+                length as isize - n as isize
+            } else {
+                0
+            };
+            (n, offset)
+        })
+        .context("in dyadic {. take")
+    }
+
+    /// `a }. w` drops `a` items from the start of `w`, or from the end if `a` is negative. Each
+    /// element of a list `a` drops along successive axes.
+    pub fn drop(a: Noun, w: Noun) -> Result<Noun> {
+        section(a, w, |count, length| {
+            let n = length.saturating_sub(count.unsigned_abs() as usize);
+            let offset = if count < 0 { 0 } else { length - n };
+            (n, offset as isize)
+        })
+        .context("in dyadic }. drop")
+    }
+
+    /// Select from `w` along its leading axes, with `axis` giving the length of the selection and
+    /// its offset into `w` from a count and the length of the axis. An atom is treated as having
+    /// an axis of length 1 for each count.
+    fn section<F>(a: Noun, w: Noun, axis: F) -> Result<Noun>
+    where
+        F: Fn(IntegerElt, usize) -> (usize, isize),
+    {
+        let counts = a.to_integers()?;
+        let w = match w {
+            Noun::Atom(_) => w.with_shape(vec![1; counts.len()])?,
+            w => w,
+        };
+        let shape = w.shape().unwrap_or_default();
+        if counts.len() > shape.len() {
+            return Err(anyhow!(
+                "Length error: {} counts for an array of rank {}",
+                counts.len(),
+                shape.len()
+            ));
+        }
+        let axes = zip(counts, shape).map(|(c, &n)| axis(c, n)).collect_vec();
+        Ok(w.section(&axes))
+    }
+
+    /// `a |. w` rotates `w` by `a` positions, to the left for positive `a`. Each element of a list
+    /// `a` rotates along successive axes.
+    pub fn rotate(a: Noun, w: Noun) -> Result<Noun> {
+        rotation(a, w, None).context("in dyadic |. rotate")
+    }
+
+    /// `a |.!.f w` shifts `w` by `a` positions, filling the vacated positions with `f`.
+    pub fn shift(a: Noun, w: Noun, fill: Noun) -> Result<Noun> {
+        rotation(a, w, Some(fill)).context("in dyadic |.!.f shift")
+    }
+
+    fn rotation(a: Noun, w: Noun, fill: Option<Noun>) -> Result<Noun> {
+        let counts = a
+            .to_integers()?
+            .into_iter()
+            .map(|c| c as isize)
+            .collect_vec();
+        let rank = w.rank().unwrap_or(0);
+        if counts.len() > rank.max(1) {
+            return Err(anyhow!(
+                "Length error: {} counts for an array of rank {}",
+                counts.len(),
+                rank
+            ));
+        }
+        w.rotate(&counts, fill)
+    }
+
+    /// `a |: w` moves the axes listed in `a` to the end, in that order. Axes boxed together are
+    /// run together into a single axis, taking their diagonal.
+    pub fn transpose(a: Noun, w: Noun) -> Result<Noun> {
+        let rank = w.rank().unwrap_or(0);
+        let axis = |k: IntegerElt| {
+            let j = if k < 0 { k + rank as IntegerElt } else { k };
+            usize::try_from(j)
+                .ok()
+                .filter(|&j| j < rank)
+                .with_context(|| anyhow!("Index error: {} is not an axis of rank {}", k, rank))
+        };
+        let groups = if a.is_boxed() {
+            a.cells(0).1.into_iter().map(Noun::unbox).collect_vec()
+        } else {
+            a.cells(0).1
+        };
+        let moved = groups
+            .iter()
+            .map(|g| {
+                g.to_integers()?
+                    .into_iter()
+                    .map(axis)
+                    .collect::<Result<Vec<_>>>()
+            })
+            .collect::<Result<Vec<_>>>()
+            .context("in dyadic |: transpose")?;
+        let listed = moved.iter().flatten().copied().collect_vec();
+        if !listed.iter().all_unique() {
+            return Err(anyhow!("Domain error: an axis is listed more than once"))
+                .context("in dyadic |: transpose");
+        }
+        let axes = (0..rank)
+            .filter(|k| !listed.contains(k))
+            .map(|k| vec![k])
+            .chain(moved)
+            .collect_vec();
+        Ok(w.transpose(&axes))
+    }
+
+    /// `a /: w` sorts the items of `a` into the order that sorts `w`.
+    pub fn sort_up(a: Noun, w: Noun) -> Result<Noun> {
+        sort(a, w, false).context("in dyadic /: sort up")
+    }
+
+    /// `a \: w` sorts the items of `a` into the order that sorts `w` in descending order.
+    pub fn sort_down(a: Noun, w: Noun) -> Result<Noun> {
+        sort(a, w, true).context("in dyadic \\: sort down")
+    }
+
+    fn sort(a: Noun, w: Noun, descending: bool) -> Result<Noun> {
+        let items = a.clone().items();
+        let order = super::monads::grade(w, descending);
+        if items.len() != order.len() {
+            return Err(anyhow!(
+                "Length error: sorting {} items by {} keys",
+                items.len(),
+                order.len()
+            ));
+        }
+        if items.is_empty() {
+            return Ok(a);
+        }
+        Noun::from_items(order.into_iter().map(|i| items[i].clone()).collect())
+    }
+
+    /// Box `a` and prepend it to the boxes of `w`, boxing `w` first if it is open.
+    pub fn link(a: Noun, w: Noun) -> Result<Noun> {
+        let w = match w {
+            Noun::Array(_) if w.is_boxed() => w.items(),
+            w if w.is_boxed() => vec![w],
+            w => vec![w.enclose()],
+        };
+        Noun::from_items([vec![a.enclose()], w].concat()).context("in dyadic ; link")
+    }
+
+    /// Follow a path into nested boxes. Each box of `a` holds indices selecting along successive
+    /// axes, and the selection is opened before the next step; an open `a` is a single step.
+    pub fn fetch(a: Noun, w: Noun) -> Result<Noun> {
+        let path = match a {
+            a if a.is_boxed() => a.cells(0).1.into_iter().map(Noun::unbox).collect(),
+            a => vec![a],
+        };
+        path.into_iter()
+            .try_fold(w, |w, step| {
+                step.to_integers()?
+                    .into_iter()
+                    .try_fold(w, item)
+                    .map(Noun::unbox)
+            })
+            .context("in dyadic {:: fetch")
+    }
+
+    /// Select an item, counting negative indices back from the end.
+    fn item(w: Noun, i: IntegerElt) -> Result<Noun> {
+        let mut items = w.items();
+        let j = index(i, items.len())?;
+        Ok(items.swap_remove(j))
+    }
+
+    /// Resolve an index into `n` items, counting negative indices back from the end.
+    fn index(i: IntegerElt, n: usize) -> Result<usize> {
+        let j = if i < 0 { i + n as IntegerElt } else { i };
+        usize::try_from(j)
+            .ok()
+            .filter(|&j| j < n)
+            .with_context(|| anyhow!("Index error: {} is out of bounds for {} items", i, n))
+    }
+
+    /// `a { w` selects an item of `w`. A boxed `a` holds a path into `w`: either an index along
+    /// each successive axis, or a box for each axis holding the indices to select along it. A
+    /// further box around those indices selects everything else along that axis instead.
+    pub fn from(a: Noun, w: Noun) -> Result<Noun> {
+        let axes = match a {
+            a if a.is_boxed() => match a.unbox() {
+                path if path.is_boxed() => path.cells(0).1.into_iter().map(Noun::unbox).collect(),
+                path => path.items(),
+            },
+            a => vec![a],
+        };
+        select(w, &axes).context("in dyadic { from")
+    }
+
+    /// Select from `w` along its leading axes. An axis given by an atom is removed.
+    fn select(w: Noun, axes: &[Noun]) -> Result<Noun> {
+        let Some((axis, rest)) = axes.split_first() else {
+            return Ok(w);
+        };
+        let items = w.items();
+        let n = items.len();
+        let (indices, keep) = if axis.is_boxed() {
+            let excluded = axis
+                .clone()
+                .unbox()
+                .to_integers()?
+                .into_iter()
+                .map(|i| index(i, n))
+                .collect::<Result<Vec<_>>>()?;
+            ((0..n).filter(|j| !excluded.contains(j)).collect(), true)
+        } else {
+            let indices = axis
+                .to_integers()?
+                .into_iter()
+                .map(|i| index(i, n))
+                .collect::<Result<Vec<_>>>()?;
+            (indices, axis.rank().is_some())
+        };
+        let mut selected = indices
+            .into_iter()
+            .map(|j| select(items[j].clone(), rest))
+            .collect::<Result<Vec<_>>>()?;
+        if keep {
+            Noun::from_items(selected)
+        } else {
+            Ok(selected.remove(0))
+        }
+    }
+
     pub fn eq(a: Noun, w: Noun) -> Result<Noun> {
+        if a.numeric().is_none() || w.numeric().is_none() {
+            return compare_atoms(a, w, true).context("in dyadic = eq");
+        }
+        let ct = scalar::tolerance();
         Noun::try_promote_pair(a, w)
             .context("in dyadic = eq")?
-            .dyad(|a, w| a == w, |a, w| a == w, |a, w| a == w)
+            .dyad(
+                |a, w| a == w,
+                |a, w| a == w,
+                |a, w| a == w,
+                |a, w| a == w,
+                |a, w| scalar::tolerant_eq(a, w, ct),
+                |a, w| scalar::tolerant_eq_complex(a, w, ct),
+            )
+    }
+
+    /// `a i. w` is the index of the first item of `a` matching each cell of `w` of the rank of
+    /// those items, or `#a` where there is none.
+    pub fn index_of(a: Noun, w: Noun) -> Result<Noun> {
+        let rank = a.rank().unwrap_or(1).saturating_sub(1);
+        let items = a.items();
+        let (frame, cells) = w.cells(rank);
+        let indices = cells
+            .iter()
+            .map(|w| {
+                let i = items.iter().position(|u| matches(u, w));
+                Noun::from(i.unwrap_or(items.len()) as IntegerElt)
+            })
+            .collect();
+        Noun::from_cells(&frame, indices).context("in dyadic i. index of")
+    }
+
+    pub fn ne(a: Noun, w: Noun) -> Result<Noun> {
+        if a.numeric().is_none() || w.numeric().is_none() {
+            return compare_atoms(a, w, false).context("in dyadic ~: not-equal");
+        }
+        let ct = scalar::tolerance();
+        Noun::try_promote_pair(a, w)
+            .context("in dyadic ~: not-equal")?
+            .dyad(
+                |a, w| a != w,
+                |a, w| a != w,
+                |a, w| a != w,
+                |a, w| a != w,
+                |a, w| !scalar::tolerant_eq(a, w, ct),
+                |a, w| !scalar::tolerant_eq_complex(a, w, ct),
+            )
+    }
+
+    /// Compare atoms of which at least one side is not numeric. Characters equal only the same
+    /// character, and boxes equal boxes whose contents match.
+    fn compare_atoms(a: Noun, w: Noun, equal: bool) -> Result<Noun> {
+        let same = |a: Noun, w: Noun| match (a.is_boxed(), w.is_boxed()) {
+            (true, true) => matches(&a.unbox(), &w.unbox()),
+            _ => a == w,
+        };
+        dyad_rank(|a, w| Ok(Noun::from(same(a, w) == equal)), 0, 0, a, w)
+    }
+
+    pub fn lt(a: Noun, w: Noun) -> Result<Noun> {
+        compare(a, w, Ordering::is_lt).context("in dyadic < less than")
+    }
+
+    pub fn le(a: Noun, w: Noun) -> Result<Noun> {
+        compare(a, w, Ordering::is_le).context("in dyadic <: less or equal")
+    }
+
+    pub fn gt(a: Noun, w: Noun) -> Result<Noun> {
+        compare(a, w, Ordering::is_gt).context("in dyadic > greater than")
+    }
+
+    pub fn ge(a: Noun, w: Noun) -> Result<Noun> {
+        compare(a, w, Ordering::is_ge).context("in dyadic >: greater or equal")
+    }
+
+    /// Compare real numbers elementwise, with decimals compared tolerantly.
+    fn compare(a: Noun, w: Noun, test: fn(Ordering) -> bool) -> Result<Noun> {
+        let ct = scalar::tolerance();
+        Noun::try_promote_pair(a, w)?.real_dyad(
+            |a, w| test(a.cmp(&w)),
+            |a, w| test(a.cmp(&w)),
+            |a, w| test(a.cmp(&w)),
+            |a, w| test(a.cmp(&w)),
+            |a, w| test(scalar::tolerant_cmp(a, w, ct)),
+        )
+    }
+
+    /// `a -: w` is whether the nouns have the same shape and equal elements.
+    pub fn match_(a: Noun, w: Noun) -> Result<Noun> {
+        Ok(Noun::from(matches(&a, &w)))
+    }
+
+    /// Whether the nouns have the same shape and tolerantly equal elements, looking inside boxes.
+    pub fn matches(a: &Noun, w: &Noun) -> bool {
+        if a.shape() != w.shape() {
+            return false;
+        }
+        if a.is_boxed() && w.is_boxed() {
+            let (a, w) = (a.clone().cells(0).1, w.clone().cells(0).1);
+            return a
+                .into_iter()
+                .zip(w)
+                .all(|(a, w)| matches(&a.unbox(), &w.unbox()));
+        }
+        match eq(a.clone(), w.clone()).and_then(Noun::into_boolean) {
+            Ok(equal) => !equal.elements().contains(&false),
+            Err(_) => a == w,
+        }
     }
 }
 
 type DyadFn = fn(Noun, Noun) -> anyhow::Result<Noun>;
 
-pub static DYADS: phf::Map<&'static str, DyadFn> = phf_map! {
-    "]" => dyads::same_w,
-    "[" => dyads::same_a,
-    "+" => dyads::add,
-    "-" => dyads::sub,
-    "*" => dyads::mul,
-    "%" => dyads::div,
-    "*." => dyads::and,
-    "=" => dyads::eq,
+#[derive(Debug, Copy, Clone)]
+pub struct Dyad {
+    pub f: DyadFn,
+    pub left: IntegerElt,
+    pub right: IntegerElt,
+}
+
+pub static DYADS: phf::Map<&'static str, Dyad> = phf_map! {
+    "]" => Dyad { f: dyads::same_w, left: INFINITE_RANK, right: INFINITE_RANK },
+    "[" => Dyad { f: dyads::same_a, left: INFINITE_RANK, right: INFINITE_RANK },
+    "[:" => Dyad { f: dyads::cap, left: INFINITE_RANK, right: INFINITE_RANK },
+    "+" => Dyad { f: dyads::add, left: 0, right: 0 },
+    "-" => Dyad { f: dyads::sub, left: 0, right: 0 },
+    "*" => Dyad { f: dyads::mul, left: 0, right: 0 },
+    "%" => Dyad { f: dyads::div, left: 0, right: 0 },
+    "*." => Dyad { f: dyads::lcm, left: 0, right: 0 },
+    "+." => Dyad { f: dyads::gcd, left: 0, right: 0 },
+    "=" => Dyad { f: dyads::eq, left: 0, right: 0 },
+    "i." => Dyad { f: dyads::index_of, left: INFINITE_RANK, right: INFINITE_RANK },
+    "~:" => Dyad { f: dyads::ne, left: 0, right: 0 },
+    "<" => Dyad { f: dyads::lt, left: 0, right: 0 },
+    "<:" => Dyad { f: dyads::le, left: 0, right: 0 },
+    ">" => Dyad { f: dyads::gt, left: 0, right: 0 },
+    ">:" => Dyad { f: dyads::ge, left: 0, right: 0 },
+    "<." => Dyad { f: dyads::min, left: 0, right: 0 },
+    ">." => Dyad { f: dyads::max, left: 0, right: 0 },
+    "|" => Dyad { f: dyads::residue, left: 0, right: 0 },
+    "^." => Dyad { f: dyads::log, left: 0, right: 0 },
+    "%:" => Dyad { f: dyads::root, left: 0, right: 0 },
+    "+:" => Dyad { f: dyads::nor, left: 0, right: 0 },
+    "*:" => Dyad { f: dyads::nand, left: 0, right: 0 },
+    "-:" => Dyad { f: dyads::match_, left: INFINITE_RANK, right: INFINITE_RANK },
+    "^" => Dyad { f: dyads::power, left: 0, right: 0 },
+    "!" => Dyad { f: dyads::out_of, left: 0, right: 0 },
+    "$" => Dyad { f: dyads::reshape, left: 1, right: INFINITE_RANK },
+    "," => Dyad { f: dyads::append, left: INFINITE_RANK, right: INFINITE_RANK },
+    // Stitch appends corresponding items
+    ",." => Dyad { f: dyads::append, left: -1, right: -1 },
+    ",:" => Dyad { f: dyads::laminate, left: INFINITE_RANK, right: INFINITE_RANK },
+    "{." => Dyad { f: dyads::take, left: 1, right: INFINITE_RANK },
+    "}." => Dyad { f: dyads::drop, left: 1, right: INFINITE_RANK },
+    ";" => Dyad { f: dyads::link, left: INFINITE_RANK, right: INFINITE_RANK },
+    "{" => Dyad { f: dyads::from, left: 0, right: INFINITE_RANK },
+    "/:" => Dyad { f: dyads::sort_up, left: INFINITE_RANK, right: INFINITE_RANK },
+    "\\:" => Dyad { f: dyads::sort_down, left: INFINITE_RANK, right: INFINITE_RANK },
+    "|." => Dyad { f: dyads::rotate, left: 1, right: INFINITE_RANK },
+    "|:" => Dyad { f: dyads::transpose, left: 1, right: INFINITE_RANK },
+    "{::" => Dyad { f: dyads::fetch, left: 1, right: INFINITE_RANK },
+};
+
+mod adverbs {
+    use super::dyads;
+    use crate::arrays::generic_array::GenericArray;
+    use crate::arrays::noun::Noun;
+    use crate::arrays::IntegerElt;
+    use crate::verb::{dyad_rank, Operand, Verb, INFINITE_RANK};
+    use anyhow::{anyhow, Context, Result};
+
+    /// The result of inserting a primitive between the items of an empty list.
+    fn identity(u: &Verb) -> Option<Noun> {
+        match u {
+            Verb::Primitive("+" | "-" | "~:" | "+." | "<" | ">" | "|") => {
+                Some((0 as IntegerElt).into())
+            }
+            Verb::Primitive("*" | "%" | "*." | "=" | "<:" | ">:" | "^" | "!") => {
+                Some((1 as IntegerElt).into())
+            }
+            _ => None,
+        }
+    }
+
+    pub fn insert(u: &Operand, w: Noun) -> Result<Noun> {
+        let u = u.as_verb().context("in monadic / insert")?;
+        let item_shape = w
+            .shape()
+            .unwrap_or_default()
+            .get(1..)
+            .unwrap_or_default()
+            .to_vec();
+        let mut items = w.items();
+        let mut acc = match items.pop() {
+            Some(acc) => acc,
+            None => {
+                let e = identity(u).with_context(|| anyhow!("{:?} has no identity element", u))?;
+                // Inserting between empty items gives an item of identities
+                return match item_shape.as_slice() {
+                    [] => Ok(e),
+                    shape => e.reshape(shape),
+                };
+            }
+        };
+        while let Some(item) = items.pop() {
+            acc = u.dyad(item, acc)?;
+        }
+        Ok(acc)
+    }
+
+    /// `x u/ y` has the left rank of `u` and infinite right rank, so each cell of `x` meets the
+    /// whole of `y`.
+    pub fn table(u: &Operand, a: Noun, w: Noun) -> Result<Noun> {
+        u.as_verb().context("in dyadic / table")?.dyad(a, w)
+    }
+
+    /// Apply `u` to each of the given runs of items of `w`, collecting the results. With no runs,
+    /// the type and item shape of the empty result come from applying `u` to a run of no items,
+    /// or failing that it is an empty integer list.
+    fn each_run<I>(u: &Verb, w: Noun, runs: I) -> Result<Noun>
+    where
+        I: Fn(usize) -> Vec<Vec<usize>>,
+    {
+        let items = w.clone().items();
+        let runs = runs(items.len());
+        if runs.is_empty() {
+            let none = w
+                .reshape(&[0])
+                .and_then(|run| u.monad(run))
+                .and_then(|r| Noun::from_items(vec![r])?.reshape(&[0]));
+            return Ok(none.unwrap_or_else(|_| Noun::from(GenericArray::<IntegerElt>::new([]))));
+        }
+        Noun::from_items(
+            runs.into_iter()
+                .map(|run| {
+                    let run = run.into_iter().map(|i| items[i].clone()).collect();
+                    u.monad(Noun::from_items(run)?)
+                })
+                .collect::<Result<_>>()?,
+        )
+    }
+
+    pub fn prefix(u: &Operand, w: Noun) -> Result<Noun> {
+        let u = u.as_verb().context("in monadic \\ prefix")?;
+        each_run(u, w, |n| (1..=n).map(|k| (0..k).collect()).collect())
+    }
+
+    pub fn infix(u: &Operand, a: Noun, w: Noun) -> Result<Noun> {
+        let u = u.as_verb().context("in dyadic \\ infix")?;
+        let x = a.to_integer().context("in dyadic \\ infix")?;
+        let size = x.unsigned_abs() as usize;
+        each_run(u, w, |n| {
+            if x < 0 {
+                (0..n)
+                    .step_by(size)
+                    .map(|i| (i..n.min(i + size)).collect())
+                    .collect()
+            } else {
+                // Runs longer than the list do not fit anywhere
+                (0..(n + 1).saturating_sub(size))
+                    .map(|i| (i..i + size).collect())
+                    .collect()
+            }
+        })
+    }
+
+    pub fn suffix(u: &Operand, w: Noun) -> Result<Noun> {
+        let u = u.as_verb().context("in monadic \\. suffix")?;
+        each_run(u, w, |n| (0..n).map(|k| (k..n).collect()).collect())
+    }
+
+    pub fn outfix(u: &Operand, a: Noun, w: Noun) -> Result<Noun> {
+        let u = u.as_verb().context("in dyadic \\. outfix")?;
+        let x = a.to_integer().context("in dyadic \\. outfix")?;
+        let size = x.unsigned_abs() as usize;
+        each_run(u, w, |n| {
+            let starts = if x < 0 {
+                (0..n).step_by(size.max(1)).collect::<Vec<_>>()
+            } else {
+                (0..(n + 1).saturating_sub(size)).collect()
+            };
+            starts
+                .into_iter()
+                .map(|i| (0..i).chain(n.min(i + size)..n).collect())
+                .collect()
+        })
+    }
+
+    pub fn reflex(u: &Operand, w: Noun) -> Result<Noun> {
+        let u = u.as_verb().context("in monadic ~ reflex")?;
+        u.dyad(w.clone(), w)
+    }
+
+    pub fn passive(u: &Operand, a: Noun, w: Noun) -> Result<Noun> {
+        let u = u.as_verb().context("in dyadic ~ passive")?;
+        u.dyad(w, a)
+    }
+
+    pub fn amend_monad(_: &Operand, _: Noun) -> Result<Noun> {
+        Err(anyhow!(
+            "Domain error: m}} needs the new values as a left argument"
+        ))
+    }
+
+    /// `a m} w` is a copy of `w` with the positions that `m { w` would select replaced by `a`,
+    /// which is repeated to fill them if its shape is a suffix of the selection's.
+    pub fn amend(m: &Operand, a: Noun, w: Noun) -> Result<Noun> {
+        let m = match m {
+            Operand::Noun(m) => m.clone(),
+            Operand::Verb(v) => return Err(anyhow!("Expected indices to amend, got {:?}", v)),
+        };
+        let shape = w
+            .shape()
+            .context("Rank error: cannot amend an atom")
+            .context("in dyadic } amend")?
+            .to_vec();
+        let positions =
+            GenericArray::iota(&shape.iter().map(|&n| n as IntegerElt).collect::<Vec<_>>());
+        let positions = dyad_rank(dyads::from, 0, INFINITE_RANK, m, positions.into())
+            .context("in dyadic } amend")?;
+        let (selected, values) = (
+            positions.shape().unwrap_or_default(),
+            a.shape().unwrap_or_default(),
+        );
+        if !selected.ends_with(values) {
+            return Err(anyhow!(
+                "Length error: cannot amend a selection of shape {:?} with values of shape {:?}",
+                selected,
+                values
+            ))
+            .context("in dyadic } amend");
+        }
+        let values = a.cells(0).1;
+        let mut cells = w.cells(0).1;
+        for (k, p) in positions.cells(0).1.iter().enumerate() {
+            cells[p.to_integer()? as usize] = values[k % values.len()].clone();
+        }
+        Noun::from_cells(&shape, cells).context("in dyadic } amend")
+    }
+}
+
+type AdverbMonadFn = fn(&Operand, Noun) -> anyhow::Result<Noun>;
+type AdverbDyadFn = fn(&Operand, Noun, Noun) -> anyhow::Result<Noun>;
+
+#[derive(Debug, Copy, Clone)]
+pub struct Adverb {
+    pub monad: AdverbMonadFn,
+    pub dyad: AdverbDyadFn,
+}
+
+pub static ADVERBS: phf::Map<&'static str, Adverb> = phf_map! {
+    "/" => Adverb { monad: adverbs::insert, dyad: adverbs::table },
+    "\\" => Adverb { monad: adverbs::prefix, dyad: adverbs::infix },
+    "\\." => Adverb { monad: adverbs::suffix, dyad: adverbs::outfix },
+    "~" => Adverb { monad: adverbs::reflex, dyad: adverbs::passive },
+    "}" => Adverb { monad: adverbs::amend_monad, dyad: adverbs::amend },
+};
+
+mod conjunctions {
+    use super::{dyads, scalar};
+    use crate::arrays::atom::Atom;
+    use crate::arrays::noun::Noun;
+    use crate::arrays::{DecimalElt, IntegerElt};
+    use crate::verb::{Operand, Verb};
+    use anyhow::{anyhow, Context, Result};
+
+    /// `u"n` is applied to cells by the rank engine, so only needs to apply `u` to each.
+    pub fn rank(u: &Operand, _: &Operand, w: Noun) -> Result<Noun> {
+        match u {
+            Operand::Verb(u) => u.monad(w),
+            Operand::Noun(u) => Ok(u.clone()),
+        }
+    }
+
+    pub fn rank_dyad(u: &Operand, _: &Operand, a: Noun, w: Noun) -> Result<Noun> {
+        match u {
+            Operand::Verb(u) => u.dyad(a, w),
+            Operand::Noun(u) => Ok(u.clone()),
+        }
+    }
+
+    pub fn atop(u: &Operand, v: &Operand, w: Noun) -> Result<Noun> {
+        let (u, v) = (u.as_verb()?, v.as_verb()?);
+        u.monad(v.monad(w)?)
+    }
+
+    pub fn atop_dyad(u: &Operand, v: &Operand, a: Noun, w: Noun) -> Result<Noun> {
+        let (u, v) = (u.as_verb()?, v.as_verb()?);
+        u.monad(v.dyad(a, w)?)
+    }
+
+    pub fn bond(u: &Operand, v: &Operand, w: Noun) -> Result<Noun> {
+        match (u, v) {
+            (Operand::Noun(m), Operand::Verb(v)) => v.dyad(m.clone(), w),
+            (Operand::Verb(u), Operand::Noun(n)) => u.dyad(w, n.clone()),
+            (Operand::Verb(u), Operand::Verb(v)) => u.monad(v.monad(w)?),
+            _ => Err(anyhow!("Domain error: & needs at least one verb operand")),
+        }
+    }
+
+    pub fn bond_dyad(u: &Operand, v: &Operand, a: Noun, w: Noun) -> Result<Noun> {
+        match (u, v) {
+            (Operand::Verb(u), Operand::Verb(v)) => u.dyad(v.monad(a)?, v.monad(w)?),
+            // x m&v y and x u&n y apply the bonded verb x times
+            _ => {
+                let bonded = Verb::Conjunction("&", Box::new(u.clone()), Box::new(v.clone()));
+                repeat(&bonded, &a, w, |v, y| v.monad(y))
+            }
+        }
+    }
+
+    pub fn appose(u: &Operand, v: &Operand, w: Noun) -> Result<Noun> {
+        atop(u, v, w)
+    }
+
+    pub fn appose_dyad(u: &Operand, v: &Operand, a: Noun, w: Noun) -> Result<Noun> {
+        let (u, v) = (u.as_verb()?, v.as_verb()?);
+        u.dyad(v.monad(a)?, v.monad(w)?)
+    }
+
+    /// Apply `f` with `u` as many times as the power `n` says: negative powers use the obverse of
+    /// `u`, an infinite power applies it until the result stops changing, and an array of powers
+    /// gives an array of results.
+    fn repeat<F>(u: &Verb, n: &Noun, w: Noun, f: F) -> Result<Noun>
+    where
+        F: Fn(&Verb, Noun) -> Result<Noun> + Copy,
+    {
+        match n {
+            Noun::Atom(Atom::Decimal(d)) if d.is_infinite() => {
+                let u = if *d > 0.0 { u.clone() } else { u.obverse()? };
+                let mut prev = w;
+                loop {
+                    let next = f(&u, prev.clone())?;
+                    // NaN never matches itself, so an identical result counts as settled too
+                    if dyads::matches(&next, &prev) || next.total_cmp(&prev).is_eq() {
+                        return Ok(next);
+                    }
+                    prev = next;
+                }
+            }
+            Noun::Atom(_) => {
+                let k = n.to_integer()?;
+                let u = if k < 0 { u.obverse()? } else { u.clone() };
+                (0..k.unsigned_abs()).try_fold(w, |y, _| f(&u, y))
+            }
+            Noun::Array(_) => {
+                let (frame, powers) = n.clone().cells(0);
+                let results = powers
+                    .iter()
+                    .map(|k| repeat(u, k, w.clone(), f))
+                    .collect::<Result<_>>()?;
+                Noun::from_cells(&frame, results)
+            }
+        }
+    }
+
+    pub fn power(u: &Operand, n: &Operand, w: Noun) -> Result<Noun> {
+        let u = u.as_verb().context("in monadic ^: power")?;
+        let n = match n {
+            Operand::Noun(n) => n.clone(),
+            Operand::Verb(v) => v.monad(w.clone())?,
+        };
+        repeat(u, &n, w, |u, y| u.monad(y))
+    }
+
+    pub fn power_dyad(u: &Operand, n: &Operand, a: Noun, w: Noun) -> Result<Noun> {
+        let u = u.as_verb().context("in dyadic ^: power")?;
+        let n = match n {
+            Operand::Noun(n) => n.clone(),
+            Operand::Verb(v) => v.dyad(a.clone(), w.clone())?,
+        };
+        repeat(u, &n, w, |u, y| u.dyad(a.clone(), y))
+    }
+
+    /// `u!.t` applies `u` with comparison tolerance `t`, except that `|.!.f` shifts in the fill
+    /// `f` rather than rotating.
+    pub fn fit(u: &Operand, t: &Operand, w: Noun) -> Result<Noun> {
+        let u = u.as_verb().context("in monadic !. fit")?;
+        if let (Verb::Primitive("|."), Operand::Noun(f)) = (u, t) {
+            return dyads::shift(Noun::from(-1 as IntegerElt), w, f.clone());
+        }
+        let ct = read_tolerance(t).context("in monadic !. fit")?;
+        scalar::with_tolerance(ct, || u.monad(w))
+    }
+
+    pub fn fit_dyad(u: &Operand, t: &Operand, a: Noun, w: Noun) -> Result<Noun> {
+        let u = u.as_verb().context("in dyadic !. fit")?;
+        if let (Verb::Primitive("|."), Operand::Noun(f)) = (u, t) {
+            return dyads::shift(a, w, f.clone());
+        }
+        let ct = read_tolerance(t).context("in dyadic !. fit")?;
+        scalar::with_tolerance(ct, || u.dyad(a, w))
+    }
+
+    /// Tolerances range from exact comparison up to 2^-34.
+    fn read_tolerance(t: &Operand) -> Result<DecimalElt> {
+        let ct = match t {
+            Operand::Noun(t) => t.to_decimal()?,
+            Operand::Verb(v) => return Err(anyhow!("Expected a tolerance, got {:?}", v)),
+        };
+        if (0.0..=5.820_766_091_346_741e-11).contains(&ct) {
+            Ok(ct)
+        } else {
+            Err(anyhow!("Domain error: {} is not a valid tolerance", ct))
+        }
+    }
+}
+
+type ConjunctionMonadFn = fn(&Operand, &Operand, Noun) -> anyhow::Result<Noun>;
+type ConjunctionDyadFn = fn(&Operand, &Operand, Noun, Noun) -> anyhow::Result<Noun>;
+
+#[derive(Debug, Copy, Clone)]
+pub struct Conjunction {
+    pub monad: ConjunctionMonadFn,
+    pub dyad: ConjunctionDyadFn,
+}
+
+pub static CONJUNCTIONS: phf::Map<&'static str, Conjunction> = phf_map! {
+    "\"" => Conjunction { monad: conjunctions::rank, dyad: conjunctions::rank_dyad },
+    "@" => Conjunction { monad: conjunctions::atop, dyad: conjunctions::atop_dyad },
+    "@:" => Conjunction { monad: conjunctions::atop, dyad: conjunctions::atop_dyad },
+    "&" => Conjunction { monad: conjunctions::bond, dyad: conjunctions::bond_dyad },
+    "&:" => Conjunction { monad: conjunctions::appose, dyad: conjunctions::appose_dyad },
+    "^:" => Conjunction { monad: conjunctions::power, dyad: conjunctions::power_dyad },
+    "!." => Conjunction { monad: conjunctions::fit, dyad: conjunctions::fit_dyad },
+};
+
+/// Pairs of primitives that undo each other monadically, used for negative powers.
+pub static OBVERSES: phf::Map<&'static str, &'static str> = phf_map! {
+    "]" => "]",
+    "[" => "[",
+    "+" => "+",
+    "-" => "-",
+    "%" => "%",
+    "+:" => "-:",
+    "-:" => "+:",
+    "*:" => "%:",
+    "%:" => "*:",
+    ">:" => "<:",
+    "<:" => ">:",
+    "^" => "^.",
+    "^." => "^",
+    "<" => ">",
+    ">" => "<",
+    "|." => "|.",
+    "|:" => "|:",
 };
+
+#[cfg(test)]
+mod tests {
+    use crate::arrays::generic_array::GenericArray;
+    use crate::arrays::noun::Noun;
+    use crate::arrays::{DecimalElt, ExtendedElt, IntegerElt, RationalElt};
+    use crate::testing::list;
+    use crate::verb::{Operand, Verb};
+
+    fn monad(u: &'static str, w: Noun) -> Noun {
+        Verb::Primitive(u).monad(w).unwrap()
+    }
+
+    fn dyad(u: &'static str, a: Noun, w: Noun) -> Noun {
+        Verb::Primitive(u).dyad(a, w).unwrap()
+    }
+
+    fn int(w: IntegerElt) -> Noun {
+        Noun::from(w)
+    }
+
+    #[test]
+    fn box_and_open() {
+        let boxed = monad("<", list(&[1, 2]));
+        assert!(boxed.is_boxed());
+        assert_eq!(boxed.shape(), None);
+        assert_eq!(monad(">", boxed), list(&[1, 2]));
+        assert_eq!(monad(">", int(3)), int(3));
+    }
+
+    #[test]
+    fn link_and_raze() {
+        let linked = dyad(";", list(&[1, 2]), int(3));
+        assert_eq!(linked.shape(), Some(&[2][..]));
+        let linked = dyad(";", int(0), linked);
+        assert_eq!(linked.shape(), Some(&[3][..]));
+        assert_eq!(monad(";", linked), list(&[0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn fetch_follows_a_path() {
+        let inner = dyad(";", list(&[5, 6]), int(7));
+        let w = dyad(";", int(1), monad("<", inner.clone()));
+        assert_eq!(dyad("{::", int(1), w.clone()), inner);
+        assert_eq!(
+            dyad("{::", dyad(";", int(1), int(0)), w.clone()),
+            list(&[5, 6])
+        );
+        assert_eq!(dyad("{::", dyad(";", int(-1), int(-1)), w.clone()), int(7));
+        assert!(Verb::Primitive("{::").dyad(int(2), w).is_err());
+    }
+
+    fn extended(w: &str) -> Noun {
+        Noun::from(w.parse::<ExtendedElt>().unwrap())
+    }
+
+    #[test]
+    fn extended_power_is_exact() {
+        let result = dyad("^", extended("2"), extended("100"));
+        assert_eq!(result, extended("1267650600228229401496703205376"));
+    }
+
+    #[test]
+    fn out_of() {
+        let result = dyad("!", extended("20"), extended("100"));
+        assert_eq!(result, extended("535983370403809682970"));
+        assert_eq!(
+            dyad("=", dyad("!", int(2), int(5)), int(10)),
+            Noun::from(true)
+        );
+        assert_eq!(dyad("!", extended("2"), extended("-3")), extended("6"));
+    }
+
+    fn rational(n: IntegerElt, q: IntegerElt) -> Noun {
+        Noun::from(RationalElt::new(n.into(), q.into()))
+    }
+
+    #[test]
+    fn exact_division() {
+        assert_eq!(dyad("%", extended("1"), extended("3")), rational(1, 3));
+        assert_eq!(dyad("%", rational(1, 2), rational(3, 4)), rational(2, 3));
+        assert_eq!(dyad("+", rational(1, 3), rational(1, 6)), rational(1, 2));
+        assert_eq!(dyad("*", rational(2, 3), extended("3")), rational(2, 1));
+        assert_eq!(dyad("^", extended("2"), extended("-1")), rational(1, 2));
+        assert_eq!(
+            dyad("%", rational(1, 2), extended("0")),
+            Noun::from(f64::INFINITY)
+        );
+        assert_eq!(
+            dyad("+", rational(1, 2), Noun::from(0.25)),
+            Noun::from(0.75)
+        );
+    }
+
+    #[test]
+    fn integer_overflow_promotes() {
+        let (max, min) = (int(IntegerElt::MAX), int(IntegerElt::MIN));
+        assert_eq!(dyad("+", max.clone(), int(1)), Noun::from(2f64.powi(63)));
+        assert_eq!(dyad("-", min, int(1)), Noun::from(-(2f64.powi(63))));
+        assert_eq!(dyad("*", int(1 << 62), int(4)), Noun::from(2f64.powi(64)));
+        let result = dyad("+", list(&[1, IntegerElt::MAX]), int(1));
+        assert_eq!(
+            result,
+            Noun::from(GenericArray::new(vec![2.0, 2f64.powi(63)]))
+        );
+        assert_eq!(dyad("+", max, int(-1)), int(IntegerElt::MAX - 1));
+    }
+
+    #[test]
+    fn nub_and_index_of() {
+        let w = Noun::from(GenericArray::new(vec![1.0, 2.0, 1.0 + 1e-15, 3.0]));
+        let nub = monad("~.", w.clone());
+        assert_eq!(nub, Noun::from(GenericArray::new(vec![1.0, 2.0, 3.0])));
+        assert_eq!(dyad("i.", w, list(&[3, 1, 4])), list(&[3, 0, 4]));
+        let table = monad("i.", list(&[2, 2]));
+        assert_eq!(dyad("i.", table, list(&[2, 3])), int(1));
+        assert_eq!(monad("~.", int(5)), list(&[5]));
+        assert_eq!(monad("~.", list(&[])), list(&[]));
+    }
+
+    #[test]
+    fn fit_sets_the_tolerance() {
+        let (a, w) = (Noun::from(1.0), Noun::from(1.0 + 1e-15));
+        assert_eq!(dyad("=", a.clone(), w.clone()), Noun::from(true));
+        let exact = Operand::Noun(Noun::from(0.0));
+        let eq = Verb::Conjunction(
+            "!.",
+            Box::new(Operand::Verb(Verb::Primitive("="))),
+            Box::new(exact),
+        );
+        assert_eq!(eq.dyad(a, w).unwrap(), Noun::from(false));
+    }
+
+    #[test]
+    fn whole_quotients_are_integers() {
+        assert_eq!(dyad("%", int(6), int(3)), int(2));
+        assert_eq!(dyad("%", int(3), int(2)), Noun::from(1.5));
+    }
+
+    #[test]
+    fn negate_signum_and_reciprocal() {
+        assert_eq!(monad("-", list(&[3, 0, -2])), list(&[-3, 0, 2]));
+        let min = int(IntegerElt::MIN);
+        assert_eq!(monad("-", min), Noun::from(2f64.powi(63)));
+        assert_eq!(monad("*", list(&[-3, 0, 5])), list(&[-1, 0, 1]));
+        assert_eq!(monad("*", Noun::from(-2.5)), int(-1));
+        assert_eq!(monad("*", rational(-1, 3)), int(-1));
+        assert_eq!(monad("%", int(4)), Noun::from(0.25));
+        assert_eq!(monad("%", int(0)), Noun::from(f64::INFINITY));
+        assert_eq!(monad("%", extended("3")), rational(1, 3));
+    }
+
+    #[test]
+    fn ravel_append_stitch_and_laminate() {
+        let table = monad("i.", list(&[2, 3]));
+        assert_eq!(monad(",", table.clone()), list(&[0, 1, 2, 3, 4, 5]));
+        assert_eq!(monad(",", int(7)), list(&[7]));
+        assert_eq!(dyad(",", list(&[1, 2]), int(3)), list(&[1, 2, 3]));
+        let appended = dyad(",", table.clone(), int(9));
+        assert_eq!(appended.shape(), Some(&[3, 3][..]));
+        assert_eq!(monad(",", appended), list(&[0, 1, 2, 3, 4, 5, 9, 9, 9]));
+        let stitched = dyad(",.", table.clone(), list(&[6, 7]));
+        assert_eq!(stitched.shape(), Some(&[2, 4][..]));
+        assert_eq!(monad(",", stitched), list(&[0, 1, 2, 6, 3, 4, 5, 7]));
+        let laminated = dyad(",:", list(&[1, 2]), int(0));
+        assert_eq!(laminated.shape(), Some(&[2, 2][..]));
+        assert_eq!(monad(",", laminated), list(&[1, 2, 0, 0]));
+    }
+
+    #[test]
+    fn take_and_drop_with_overtake() {
+        let w = list(&[1, 2, 3]);
+        assert_eq!(dyad("{.", int(2), w.clone()), list(&[1, 2]));
+        assert_eq!(dyad("{.", int(5), w.clone()), list(&[1, 2, 3, 0, 0]));
+        assert_eq!(dyad("{.", int(-5), w.clone()), list(&[0, 0, 1, 2, 3]));
+        assert_eq!(dyad("}.", int(1), w.clone()), list(&[2, 3]));
+        assert_eq!(dyad("}.", int(-2), w.clone()), list(&[1]));
+        assert_eq!(dyad("}.", int(5), w.clone()), list(&[]));
+        let table = monad("i.", list(&[3, 3]));
+        let corner = dyad("{.", list(&[2, -4]), table);
+        assert_eq!(corner.shape(), Some(&[2, 4][..]));
+        assert_eq!(monad(",", corner), list(&[0, 0, 1, 2, 0, 3, 4, 5]));
+        assert_eq!(dyad("{.", int(3), int(7)), list(&[7, 0, 0]));
+    }
+
+    #[test]
+    fn head_tail_behead_and_curtail() {
+        let w = list(&[1, 2, 3]);
+        assert_eq!(monad("{.", w.clone()), int(1));
+        assert_eq!(monad("{:", w.clone()), int(3));
+        assert_eq!(monad("}.", w.clone()), list(&[2, 3]));
+        assert_eq!(monad("}:", w), list(&[1, 2]));
+        assert_eq!(monad("{.", list(&[])), int(0));
+    }
+
+    #[test]
+    fn from_selects_items_and_paths() {
+        let w = list(&[5, 6, 7]);
+        assert_eq!(dyad("{", int(1), w.clone()), int(6));
+        assert_eq!(dyad("{", list(&[-1, 0]), w.clone()), list(&[7, 5]));
+        assert!(Verb::Primitive("{").dyad(int(3), w).is_err());
+        let table = monad("i.", list(&[3, 3]));
+        let path = list(&[1, 2]).enclose();
+        assert_eq!(dyad("{", path, table.clone()), int(5));
+        let others = list(&[0]).enclose().enclose().enclose();
+        let rest = dyad("{", others, table);
+        assert_eq!(rest.shape(), Some(&[2, 3][..]));
+        assert_eq!(monad(",", rest), list(&[3, 4, 5, 6, 7, 8]));
+    }
+
+    #[test]
+    fn amend_replaces_selected_items() {
+        let amend = |m: Noun| Verb::Adverb("}", Box::new(Operand::Noun(m)));
+        let w = list(&[5, 6, 7]);
+        assert_eq!(
+            amend(int(1)).dyad(int(0), w.clone()).unwrap(),
+            list(&[5, 0, 7])
+        );
+        let result = amend(list(&[0, 2])).dyad(list(&[8, 9]), w.clone());
+        assert_eq!(result.unwrap(), list(&[8, 6, 9]));
+        assert!(amend(list(&[0, 2])).dyad(list(&[1, 2, 3]), w).is_err());
+    }
+
+    #[test]
+    fn catalogue_chooses_from_each_box() {
+        let w = dyad(";", list(&[1, 2]), list(&[3, 4, 5]));
+        let result = monad("{", w);
+        assert_eq!(result.shape(), Some(&[2, 3][..]));
+        let choices = monad(">", result);
+        assert_eq!(choices.shape(), Some(&[2, 3, 2][..]));
+        let first = dyad("{", int(0), choices);
+        assert_eq!(monad(",", first), list(&[1, 3, 1, 4, 1, 5]));
+    }
+
+    #[test]
+    fn reverse_rotate_and_shift() {
+        let w = list(&[1, 2, 3]);
+        assert_eq!(monad("|.", w.clone()), list(&[3, 2, 1]));
+        assert_eq!(dyad("|.", int(1), w.clone()), list(&[2, 3, 1]));
+        assert_eq!(dyad("|.", int(-4), w.clone()), list(&[3, 1, 2]));
+        let table = monad("i.", list(&[2, 3]));
+        let rotated = dyad("|.", list(&[1, -1]), table);
+        assert_eq!(monad(",", rotated), list(&[5, 3, 4, 2, 0, 1]));
+        let shift = Verb::Conjunction(
+            "!.",
+            Box::new(Operand::Verb(Verb::Primitive("|."))),
+            Box::new(Operand::Noun(int(0))),
+        );
+        assert_eq!(shift.dyad(int(1), w.clone()).unwrap(), list(&[2, 3, 0]));
+        assert_eq!(shift.monad(w).unwrap(), list(&[0, 1, 2]));
+    }
+
+    #[test]
+    fn transpose_moves_axes() {
+        let table = monad("i.", list(&[2, 3]));
+        let transposed = monad("|:", table.clone());
+        assert_eq!(transposed.shape(), Some(&[3, 2][..]));
+        assert_eq!(monad(",", transposed.clone()), list(&[0, 3, 1, 4, 2, 5]));
+        assert_eq!(dyad("|:", int(0), table), transposed);
+        let square = monad("i.", list(&[3, 3]));
+        let diagonal = list(&[0, 1]).enclose();
+        assert_eq!(dyad("|:", diagonal.clone(), square), list(&[0, 4, 8]));
+        let cube = monad("i.", list(&[2, 2, 3]));
+        let result = dyad("|:", diagonal, cube);
+        assert_eq!(result.shape(), Some(&[3, 2][..]));
+        assert_eq!(monad(",", result), list(&[0, 9, 1, 10, 2, 11]));
+        assert!(Verb::Primitive("|:")
+            .dyad(list(&[0, 0]), list(&[1]))
+            .is_err());
+    }
+
+    #[test]
+    fn grade_and_sort() {
+        let w = list(&[3, 1, 2, 1]);
+        assert_eq!(monad("/:", w.clone()), list(&[1, 3, 2, 0]));
+        assert_eq!(monad("\\:", w.clone()), list(&[0, 2, 1, 3]));
+        assert_eq!(dyad("/:", w.clone(), w.clone()), list(&[1, 1, 2, 3]));
+        assert_eq!(dyad("\\:", list(&[7, 8, 9, 10]), w), list(&[7, 9, 8, 10]));
+        let mixed = Noun::from(GenericArray::new(vec![2.5, -1.0, 2.0]));
+        assert_eq!(monad("/:", mixed), list(&[1, 2, 0]));
+        let rows = dyad("|.", int(1), monad("i.", list(&[3, 2])));
+        let sorted = dyad("/:", rows.clone(), rows);
+        assert_eq!(monad(",", sorted), list(&[0, 1, 2, 3, 4, 5]));
+        assert!(Verb::Primitive("/:").dyad(list(&[1, 2]), int(3)).is_err());
+    }
+
+    fn adverb(a: &'static str, u: &'static str) -> Verb {
+        Verb::Adverb(a, Box::new(Operand::Verb(Verb::Primitive(u))))
+    }
+
+    #[test]
+    fn infix_longer_than_list() {
+        let boxes = adverb("\\", "<");
+        let result = boxes.dyad(int(5), list(&[1, 2, 3])).unwrap();
+        assert_eq!(result.shape(), Some(&[0][..]));
+        assert!(result.is_boxed());
+        let text = Noun::from(GenericArray::new(vec!['a', 'b', 'c']));
+        let result = adverb("\\", "]").dyad(int(5), text).unwrap();
+        let empty = GenericArray::<char>::from_shape_and_data(vec![0, 0], vec![]).unwrap();
+        assert_eq!(result, Noun::from(empty));
+        let result = boxes.dyad(int(2), list(&[]));
+        assert_eq!(result.unwrap().shape(), Some(&[0][..]));
+        let result = adverb("\\", "]").dyad(int(2), list(&[1, 2, 3]));
+        let expected = GenericArray::from_shape_and_data(vec![2, 2], vec![1, 2, 2, 3]).unwrap();
+        assert_eq!(result.unwrap(), Noun::from(expected));
+    }
+
+    #[test]
+    fn insert_over_empty_items() {
+        let empty = Noun::from(GenericArray::<IntegerElt>::iota(&[0, 3]));
+        let sum = adverb("/", "+").monad(empty).unwrap();
+        assert_eq!(sum, list(&[0, 0, 0]));
+        let sum = adverb("/", "+").monad(list(&[])).unwrap();
+        assert_eq!(sum, int(0));
+    }
+
+    #[test]
+    fn power_limit_settles_on_nan() {
+        let limit = Operand::Noun(Noun::from(DecimalElt::INFINITY));
+        let same = Box::new(Operand::Verb(Verb::Primitive("]")));
+        let result =
+            Verb::Conjunction("^:", same, Box::new(limit)).monad(Noun::from(DecimalElt::NAN));
+        assert!(matches!(result.unwrap().to_decimal(), Ok(d) if d.is_nan()));
+    }
+
+    #[test]
+    fn obverse_of_bond_only() {
+        let one = || Box::new(Operand::Noun(Noun::from(1 as IntegerElt)));
+        let plus = || Box::new(Operand::Verb(Verb::Primitive("+")));
+        assert!(Verb::Conjunction("&", one(), plus()).obverse().is_ok());
+        for c in ["@", "@:", "&:"] {
+            assert!(Verb::Conjunction(c, one(), plus()).obverse().is_err());
+            assert!(Verb::Conjunction(c, plus(), one()).obverse().is_err());
+        }
+    }
+
+    #[test]
+    fn iota_reverses_negative_axes() {
+        let iota = Verb::Primitive("i.");
+        assert_eq!(iota.monad(list(&[-3])).unwrap(), list(&[2, 1, 0]));
+        let expected = GenericArray::from_shape_and_data(vec![2, 3], vec![2, 1, 0, 5, 4, 3]);
+        let result = iota.monad(list(&[2, -3])).unwrap();
+        assert_eq!(result, Noun::from(expected.unwrap()));
+        let head = Verb::Primitive("{.").monad(iota.monad(list(&[-3])).unwrap());
+        assert_eq!(head.unwrap(), Noun::from(2 as IntegerElt));
+    }
+
+    #[test]
+    fn eq_compares_characters_and_boxes() {
+        let text = |s: &str| Noun::from(GenericArray::new(s.chars().collect::<Vec<_>>()));
+        let result = Verb::Primitive("=").dyad(text("abc"), text("abd")).unwrap();
+        assert_eq!(
+            result,
+            Noun::from(GenericArray::new(vec![true, true, false]))
+        );
+        let result = Verb::Primitive("~:")
+            .dyad(text("abc"), list(&[1, 2, 3]))
+            .unwrap();
+        assert_eq!(result, Noun::from(GenericArray::new(vec![true; 3])));
+        let boxed = |n: Noun| Verb::Primitive("<").monad(n).unwrap();
+        let result = Verb::Primitive("=").dyad(boxed(list(&[1, 2])), boxed(list(&[1, 2])));
+        assert_eq!(result.unwrap(), Noun::from(true));
+    }
+
+    #[test]
+    fn magnitude_of_least_integer() {
+        let result = Verb::Primitive("|")
+            .monad(Noun::from(IntegerElt::MIN))
+            .unwrap();
+        assert_eq!(result, Noun::from(-(IntegerElt::MIN as DecimalElt)));
+        let result = Verb::Primitive("|").monad(list(&[-2, 3])).unwrap();
+        assert_eq!(result, list(&[2, 3]));
+    }
+
+    #[test]
+    fn gcd_of_least_integer() {
+        let least = || Noun::from(IntegerElt::MIN);
+        let expected = Noun::from(-(IntegerElt::MIN as DecimalElt));
+        let result = Verb::Primitive("+.").dyad(Noun::from(0 as IntegerElt), least());
+        assert_eq!(result.unwrap(), expected);
+        let result = Verb::Primitive("*.").dyad(least(), least());
+        assert_eq!(result.unwrap(), expected);
+        let result = Verb::Primitive("+.").dyad(Noun::from(6 as IntegerElt), least());
+        assert_eq!(result.unwrap(), Noun::from(2 as IntegerElt));
+    }
+
+    #[test]
+    fn reshape_to_empty_shape() {
+        let reshape = Verb::Primitive("$");
+        let five = || Noun::from(5 as IntegerElt);
+        assert_eq!(reshape.dyad(list(&[]), five()).unwrap(), five());
+        let empty = Noun::from(GenericArray::<char>::new(vec![]));
+        assert_eq!(reshape.dyad(empty, five()).unwrap(), five());
+        assert_eq!(reshape.dyad(list(&[]), list(&[5, 6])).unwrap(), five());
+    }
+
+    #[test]
+    fn items_of_rank_0_arrays() {
+        let zero = monad("i.", list(&[]));
+        assert_eq!(adverb("/", "+").monad(zero).unwrap(), int(0));
+        let five = dyad("{.", list(&[]), int(5));
+        assert_eq!(five.clone().items(), vec![int(5)]);
+        assert_eq!(adverb("/", "+").monad(five).unwrap(), int(5));
+    }
+
+    #[test]
+    fn whole_exact_quotients_are_extended() {
+        assert_eq!(dyad("%", extended("6"), extended("3")), extended("2"));
+        assert_eq!(dyad("%", rational(1, 2), rational(1, 4)), extended("2"));
+        assert_eq!(dyad("%", extended("3"), extended("2")), rational(3, 2));
+        let quotients = dyad("%", extended("6"), list(&[2, 3]));
+        assert_eq!(quotients, dyad(",", extended("3"), extended("2")));
+    }
+
+    #[test]
+    fn nor_and_nand_need_booleans() {
+        let result = dyad("+:", list(&[0, 0, 1]), list(&[0, 1, 1]));
+        assert_eq!(
+            result,
+            Noun::from(GenericArray::new(vec![true, false, false]))
+        );
+        assert_eq!(dyad("*:", int(1), Noun::from(1.0)), Noun::from(false));
+        assert!(Verb::Primitive("+:").dyad(int(2), int(3)).is_err());
+        assert!(Verb::Primitive("*:").dyad(int(1), Noun::from(0.5)).is_err());
+    }
+
+    #[test]
+    fn out_of_large_decimals() {
+        let infinity = Noun::from(f64::INFINITY);
+        assert_eq!(dyad("!", Noun::from(1e15), Noun::from(2e15)), infinity);
+        assert_eq!(dyad("!", Noun::from(1e15), Noun::from(2.5e15)), infinity);
+        let half = dyad("!", Noun::from(0.5), Noun::from(1e6))
+            .to_decimal()
+            .unwrap();
+        let expected = 1000.0 / std::f64::consts::PI.sqrt() * 2.0;
+        assert!((half / expected - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn empty_arguments_take_the_other_type() {
+        let empty = Noun::from(GenericArray::<char>::new(vec![]));
+        assert_eq!(dyad(",", empty.clone(), list(&[1, 2])), list(&[1, 2]));
+        assert_eq!(dyad(",", list(&[1, 2]), empty.clone()), list(&[1, 2]));
+        let table = dyad(",", empty.clone(), monad("i.", list(&[2, 2])));
+        assert_eq!(table.shape(), Some(&[3, 2][..]));
+        assert_eq!(monad(",", table), list(&[0, 0, 0, 1, 2, 3]));
+        let laminated = dyad(",:", empty, list(&[1, 2]));
+        assert_eq!(monad(",", laminated), list(&[0, 0, 1, 2]));
+    }
+}
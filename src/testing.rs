@@ -0,0 +1,8 @@
+use crate::arrays::generic_array::GenericArray;
+use crate::arrays::noun::Noun;
+use crate::arrays::IntegerElt;
+
+/// A list of integers, the most common argument in tests.
+pub fn list(v: &[IntegerElt]) -> Noun {
+    Noun::from(GenericArray::new(v.to_vec()))
+}
@@ -0,0 +1,328 @@
+use crate::arrays::array::Array;
+use crate::arrays::atom::Atom;
+use crate::arrays::generic_array::GenericArray;
+use crate::arrays::noun::Noun;
+use crate::arrays::{DecimalElt, IntegerElt};
+use crate::primitives::{ADVERBS, CONJUNCTIONS, DYADS, MONADS, OBVERSES};
+use anyhow::{anyhow, Context, Result};
+
+/// Larger than the rank of any noun, so that a verb of this rank always applies to its whole
+/// argument.
+pub const INFINITE_RANK: IntegerElt = IntegerElt::MAX;
+
+/// The monadic, left and right ranks of a verb.
+pub type Ranks = (IntegerElt, IntegerElt, IntegerElt);
+
+/// Either argument of a modifier: adverbs and conjunctions accept nouns as well as verbs.
+#[derive(Debug, Clone)]
+pub enum Operand {
+    Noun(Noun),
+    Verb(Verb),
+}
+
+impl Operand {
+    pub fn as_verb(&self) -> Result<&Verb> {
+        match self {
+            Operand::Verb(v) => Ok(v),
+            Operand::Noun(n) => Err(anyhow!("Expected a verb operand, got {:?}", n)),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Verb {
+    /// A primitive looked up by name in `MONADS` and `DYADS`.
+    Primitive(&'static str),
+    /// A verb derived by applying an entry of `ADVERBS` to an operand, e.g. `+/`.
+    Adverb(&'static str, Box<Operand>),
+    /// A verb derived by applying an entry of `CONJUNCTIONS` to two operands, e.g. `+@-`.
+    Conjunction(&'static str, Box<Operand>, Box<Operand>),
+    /// `(f g) y` is `y f g y`; `x (f g) y` is `x f g y`.
+    Hook(Box<Verb>, Box<Verb>),
+    /// `(f g h) y` is `(f y) g (h y)`; `x (f g h) y` is `(x f y) g (x h y)`.
+    /// A noun in the left tine is used as-is.
+    Fork(Box<Operand>, Box<Verb>, Box<Verb>),
+    /// `([: g h) y` is `g h y`; `x ([: g h) y` is `g x h y`.
+    CappedFork(Box<Verb>, Box<Verb>),
+}
+
+impl Verb {
+    /// The monadic, left and right ranks of the verb.
+    pub fn ranks(&self) -> Result<Ranks> {
+        use Operand as O;
+        use Verb::*;
+        const I: IntegerElt = INFINITE_RANK;
+        Ok(match self {
+            Primitive(p) => {
+                let m = MONADS.get(p).map_or(I, |m| m.rank);
+                let (l, r) = DYADS.get(p).map_or((I, I), |d| (d.left, d.right));
+                (m, l, r)
+            }
+            Adverb("/", u) => (I, u.as_verb()?.ranks()?.1, I),
+            Adverb("\\" | "\\.", _) => (I, 0, I),
+            Conjunction("\"", _, n) => match n.as_ref() {
+                O::Noun(n) => read_ranks(n)?,
+                O::Verb(v) => v.ranks()?,
+            },
+            Conjunction("@", _, v) => v.as_verb()?.ranks()?,
+            Conjunction("&", u, v) => match (u.as_ref(), v.as_ref()) {
+                (O::Noun(_), O::Verb(v)) => (v.ranks()?.2, I, I),
+                (O::Verb(u), O::Noun(_)) => (u.ranks()?.1, I, I),
+                (_, v) => {
+                    let m = v.as_verb()?.ranks()?.0;
+                    (m, m, m)
+                }
+            },
+            Conjunction("^:", u, n) if matches!(n.as_ref(), O::Noun(_)) => u.as_verb()?.ranks()?,
+            Conjunction("!.", u, _) => u.as_verb()?.ranks()?,
+            _ => (I, I, I),
+        })
+    }
+
+    pub fn monad(&self, w: Noun) -> Result<Noun> {
+        let (rank, _, _) = self.ranks()?;
+        match self {
+            // Rank 0 primitives work elementwise over whole arrays, so need no splitting
+            Verb::Primitive(_) if rank == 0 => self.monad_cell(w),
+            _ => monad_rank(|w| self.monad_cell(w), rank, w),
+        }
+    }
+
+    pub fn dyad(&self, a: Noun, w: Noun) -> Result<Noun> {
+        let (_, left, right) = self.ranks()?;
+        match self {
+            Verb::Primitive(_) if left == 0 && right == 0 => self.dyad_cells(a, w),
+            _ => dyad_rank(|a, w| self.dyad_cells(a, w), left, right, a, w),
+        }
+    }
+
+    /// Apply the verb to a single cell of its monadic rank.
+    fn monad_cell(&self, w: Noun) -> Result<Noun> {
+        use Verb::*;
+        match self {
+            Primitive(p) => (MONADS
+                .get(p)
+                .with_context(|| anyhow!("Unknown monad {}", p))?
+                .f)(w),
+            Adverb(a, u) => (ADVERBS[a].monad)(u, w),
+            Conjunction(c, u, v) => (CONJUNCTIONS[c].monad)(u, v, w),
+            Hook(f, g) => f.dyad(w.clone(), g.monad(w)?),
+            Fork(f, g, h) => {
+                let right = h.monad(w.clone())?;
+                let left = match f.as_ref() {
+                    Operand::Noun(n) => n.clone(),
+                    Operand::Verb(f) => f.monad(w)?,
+                };
+                g.dyad(left, right)
+            }
+            CappedFork(g, h) => g.monad(h.monad(w)?),
+        }
+    }
+
+    /// Apply the verb to a pair of cells of its dyadic ranks.
+    fn dyad_cells(&self, a: Noun, w: Noun) -> Result<Noun> {
+        use Verb::*;
+        match self {
+            Primitive(p) => (DYADS
+                .get(p)
+                .with_context(|| anyhow!("Unknown dyad {}", p))?
+                .f)(a, w),
+            Adverb(adv, u) => (ADVERBS[adv].dyad)(u, a, w),
+            Conjunction(c, u, v) => (CONJUNCTIONS[c].dyad)(u, v, a, w),
+            Hook(f, g) => f.dyad(a, g.monad(w)?),
+            Fork(f, g, h) => {
+                let right = h.dyad(a.clone(), w.clone())?;
+                let left = match f.as_ref() {
+                    Operand::Noun(n) => n.clone(),
+                    Operand::Verb(f) => f.dyad(a, w)?,
+                };
+                g.dyad(left, right)
+            }
+            CappedFork(g, h) => g.monad(h.dyad(a, w)?),
+        }
+    }
+}
+
+impl Verb {
+    /// The verb that undoes this one, where it is known.
+    pub fn obverse(&self) -> Result<Verb> {
+        use Operand as O;
+        use Verb::*;
+        let bond = |v: &'static str, n: &Noun, left: bool| {
+            let (v, n) = (O::Verb(Primitive(v)), O::Noun(n.clone()));
+            let (u, v) = if left { (n, v) } else { (v, n) };
+            Conjunction("&", Box::new(u), Box::new(v))
+        };
+        Ok(match self {
+            Primitive(p) => OBVERSES
+                .get(p)
+                .map(|&q| Primitive(q))
+                .with_context(|| anyhow!("No obverse for {}", p))?,
+            Conjunction(c @ ("@" | "@:" | "&" | "&:"), u, v) => {
+                match (*c, u.as_ref(), v.as_ref()) {
+                    (_, O::Verb(u), O::Verb(v)) => Conjunction(
+                        c,
+                        Box::new(O::Verb(v.obverse()?)),
+                        Box::new(O::Verb(u.obverse()?)),
+                    ),
+                    // m&+ and +&m add m, so both are undone by -&m
+                    ("&", O::Noun(m), O::Verb(Primitive("+")))
+                    | ("&", O::Verb(Primitive("+")), O::Noun(m)) => bond("-", m, false),
+                    ("&", O::Noun(m), O::Verb(Primitive("*")))
+                    | ("&", O::Verb(Primitive("*")), O::Noun(m)) => bond("%", m, false),
+                    ("&", O::Verb(Primitive("-")), O::Noun(m)) => bond("+", m, false),
+                    ("&", O::Verb(Primitive("%")), O::Noun(m)) => bond("*", m, false),
+                    // m&- and m&% are their own inverses
+                    ("&", O::Noun(m), O::Verb(Primitive(v @ ("-" | "%")))) => bond(v, m, true),
+                    _ => return Err(anyhow!("No obverse for {:?}", self)),
+                }
+            }
+            Conjunction("^:", u, n) => match n.as_ref() {
+                O::Noun(n) => {
+                    let powers = n.to_integers()?.into_iter().map(|k| -k).collect::<Vec<_>>();
+                    let n = match n {
+                        Noun::Atom(_) => Noun::from(powers[0]),
+                        Noun::Array(_) => Noun::from(GenericArray::new(powers)),
+                    };
+                    Conjunction("^:", u.clone(), Box::new(O::Noun(n)))
+                }
+                O::Verb(_) => return Err(anyhow!("No obverse for {:?}", self)),
+            },
+            _ => return Err(anyhow!("No obverse for {:?}", self)),
+        })
+    }
+}
+
+/// Read ranks given as a noun, as in the right operand of `"`. One rank applies to all three
+/// cases, and two ranks are the left and right ranks, with the right also used monadically.
+pub fn read_ranks(n: &Noun) -> Result<Ranks> {
+    let ranks = match n {
+        Noun::Atom(Atom::Decimal(d)) => vec![read_rank(*d)?],
+        Noun::Array(Array::Decimal(d)) => d
+            .raw_data()
+            .iter()
+            .map(|&d| read_rank(d))
+            .collect::<Result<_>>()?,
+        n => n.to_integers()?,
+    };
+    match *ranks.as_slice() {
+        [r] => Ok((r, r, r)),
+        [l, r] => Ok((r, l, r)),
+        [m, l, r] => Ok((m, l, r)),
+        _ => Err(anyhow!("Length error: a rank has at most three elements")),
+    }
+}
+
+fn read_rank(d: DecimalElt) -> Result<IntegerElt> {
+    if d == DecimalElt::INFINITY {
+        Ok(INFINITE_RANK)
+    } else if d == DecimalElt::NEG_INFINITY {
+        Ok(-INFINITE_RANK)
+    } else if d.fract() == 0.0 {
+        Ok(d as IntegerElt)
+    } else {
+        Err(anyhow!("Domain error: {} is not a rank", d))
+    }
+}
+
+/// Clamp a verb rank to the rank of its argument, counting negative ranks back from it.
+fn effective_rank(rank: IntegerElt, noun_rank: usize) -> usize {
+    if rank < 0 {
+        noun_rank.saturating_sub(rank.unsigned_abs() as usize)
+    } else {
+        noun_rank.min(rank as usize)
+    }
+}
+
+/// Apply `f` to each cell of `w` of the given rank, assembling the results in the frame.
+pub fn monad_rank<F>(f: F, rank: IntegerElt, w: Noun) -> Result<Noun>
+where
+    F: Fn(Noun) -> Result<Noun>,
+{
+    let (frame, cells) = w.clone().cells(effective_rank(rank, w.rank().unwrap_or(0)));
+    if frame.is_empty() {
+        return f(w);
+    }
+    Noun::from_cells(&frame, cells.into_iter().map(f).collect::<Result<_>>()?)
+}
+
+/// Apply `f` to corresponding cells of `a` and `w` of the given ranks, assembling the results in
+/// the longer of the two frames. The shorter frame must be a prefix of the longer, and each of
+/// its cells is paired with every cell of the longer frame that it prefixes.
+pub fn dyad_rank<F>(f: F, left: IntegerElt, right: IntegerElt, a: Noun, w: Noun) -> Result<Noun>
+where
+    F: Fn(Noun, Noun) -> Result<Noun>,
+{
+    let left = effective_rank(left, a.rank().unwrap_or(0));
+    let right = effective_rank(right, w.rank().unwrap_or(0));
+    let (fa, ca) = a.cells(left);
+    let (fw, cw) = w.cells(right);
+    let common = fa.len().min(fw.len());
+    if fa[..common] != fw[..common] {
+        return Err(anyhow!(
+            "Length error: frames {:?} and {:?} do not agree",
+            fa,
+            fw
+        ));
+    }
+
+    let frame = if fa.len() > fw.len() { fa } else { fw };
+    let n = frame.iter().product::<usize>();
+    let (ra, rw) = (n / ca.len().max(1), n / cw.len().max(1));
+    let results = (0..n)
+        .map(|i| f(ca[i / ra].clone(), cw[i / rw].clone()))
+        .collect::<Result<_>>()?;
+    Noun::from_cells(&frame, results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::list;
+
+    fn table(shape: &[usize], v: &[IntegerElt]) -> Noun {
+        Noun::from(GenericArray::from_shape_and_data(shape.to_vec(), v.to_vec()).unwrap())
+    }
+
+    fn rank(u: Verb, n: Noun) -> Verb {
+        Verb::Conjunction("\"", Box::new(Operand::Verb(u)), Box::new(Operand::Noun(n)))
+    }
+
+    fn sum() -> Verb {
+        Verb::Adverb("/", Box::new(Operand::Verb(Verb::Primitive("+"))))
+    }
+
+    #[test]
+    fn monad_on_cells() {
+        let rows = rank(sum(), Noun::from(1 as IntegerElt));
+        let w = table(&[2, 3], &[0, 1, 2, 3, 4, 5]);
+        assert_eq!(rows.monad(w.clone()).unwrap(), list(&[3, 12]));
+        let items = rank(sum(), Noun::from(-1 as IntegerElt));
+        assert_eq!(items.monad(w).unwrap(), list(&[3, 12]));
+    }
+
+    #[test]
+    fn results_are_filled() {
+        let iota = rank(Verb::Primitive("i."), Noun::from(0 as IntegerElt));
+        let result = iota.monad(list(&[1, 2])).unwrap();
+        assert_eq!(result, table(&[2, 2], &[0, 0, 0, 1]));
+    }
+
+    #[test]
+    fn dyad_frames_agree() {
+        let plus = rank(Verb::Primitive("+"), list(&[0, 1]));
+        let w = table(&[2, 3], &[0, 1, 2, 3, 4, 5]);
+        let result = plus.dyad(list(&[1, 2]), w.clone()).unwrap();
+        assert_eq!(result, table(&[2, 3], &[1, 2, 3, 5, 6, 7]));
+        let result = Verb::Primitive("+").dyad(list(&[1, 2, 3]), w);
+        assert!(format!("{:#}", result.unwrap_err()).contains("Length error"));
+    }
+
+    #[test]
+    fn ranks_are_clamped() {
+        assert_eq!(effective_rank(INFINITE_RANK, 2), 2);
+        assert_eq!(effective_rank(1, 2), 1);
+        assert_eq!(effective_rank(-1, 2), 1);
+        assert_eq!(effective_rank(-3, 2), 0);
+    }
+}